@@ -0,0 +1,86 @@
+// Helpers of the build scripts of the contracts to generate the info of the
+// build that is returned by the Info query of the programs (the git revision,
+// the hash of the IDL and its services), and to check that it is not stale
+use sha2::{Digest, Sha256};
+use std::{fs, io::ErrorKind, path::Path, process::Command};
+
+// Generates the constants GIT_REV, IDL_HASH and SERVICES from the committed IDL
+// (app/build.rs), the build fails if the IDL can't be read or parsed. An IDL that
+// doesn't exist yet is empty, the wasm build creates it and fails (see "check_idl")
+pub fn program_info(idl_path: &Path) -> String {
+    println!("cargo:rerun-if-changed={}", idl_path.display());
+
+    let idl = match fs::read_to_string(idl_path) {
+        Ok(idl) => idl,
+        Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+        Err(error) => panic!("Can't read the IDL {}: {error}", idl_path.display())
+    };
+    let services: Vec<String> = idl_compat::Idl::parse(&idl)
+        .unwrap_or_else(|error| panic!("Can't parse the IDL {}: {error}", idl_path.display()))
+        .services
+        .iter()
+        .map(|(name, service)| {
+            let methods = |is_query: bool| service
+                .funcs()
+                .iter()
+                .filter(|func| func.is_query() == is_query)
+                .map(|func| format!("{:?}", func.name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("    ({name:?}, &[{}], &[{}])", methods(false), methods(true))
+        })
+        .collect();
+
+    format!(
+        "pub const GIT_REV: &str = {:?};\n\
+        pub const IDL_HASH: [u8; 32] = {:?};\n\
+        pub const SERVICES: &[(&str, &[&str], &[&str])] = &[\n{}\n];\n",
+        git_rev(),
+        idl_hash(&idl),
+        services.join(",\n")
+    )
+}
+
+// Checks that the IDL generated by the wasm build (wasm/build.rs) is the one
+// embedded in the app (IDL_HASH), if not, the IDL changed in this build and the
+// build fails, so a program with a stale Info query is never built
+pub fn check_idl(idl_path: &Path, embedded_idl_hash: [u8; 32]) {
+    let idl = fs::read_to_string(idl_path)
+        .unwrap_or_else(|error| panic!("Can't read the IDL {}: {error}", idl_path.display()));
+
+    if idl_hash(&idl) != embedded_idl_hash {
+        panic!(
+            "The IDL {} changed in this build, build again so the Info query reports the new IDL",
+            idl_path.display()
+        );
+    }
+}
+
+// Returns the sha256 hash of the IDL
+pub fn idl_hash(idl: &str) -> [u8; 32] {
+    Sha256::digest(idl.as_bytes()).into()
+}
+
+// Returns the git revision of the sources, "unknown" if git is not available
+pub fn git_rev() -> String {
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        println!("cargo:rerun-if-changed={git_dir}/HEAD");
+
+        if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+            println!("cargo:rerun-if-changed={git_dir}/{head_ref}");
+        }
+    }
+
+    git(&["rev-parse", "HEAD"]).unwrap_or_else(|| "unknown".to_string())
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+}
@@ -0,0 +1,28 @@
+// Builds the wasm of the contracts for the tools and the tests that deploy them
+// in gtest (the gas bench, the scenario runner and the tests of the proxy)
+use std::{
+    env,
+    fs,
+    path::Path,
+    process::Command
+};
+
+// Returns the optimized wasm of a contract, the contracts are in their own
+// workspaces, so it is built with cargo in the directory of the contract
+pub fn build(contract_dir: &Path) -> Result<Vec<u8>, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let status = Command::new(cargo)
+        .args(["build", "--release"])
+        .current_dir(contract_dir)
+        .env_remove("CARGO_TARGET_DIR")
+        .status()
+        .map_err(|error| format!("Can't run cargo: {error}"))?;
+
+    if !status.success() {
+        return Err(format!("Can't build {}", contract_dir.display()));
+    }
+
+    fs::read(contract_dir.join("target/wasm32-unknown-unknown/release/wasm.opt.wasm"))
+        .map_err(|error| format!("Can't read the wasm of {}: {error}", contract_dir.display()))
+}
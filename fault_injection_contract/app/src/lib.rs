@@ -0,0 +1,47 @@
+#![no_std]
+use sails_rs::{
+    prelude::*,
+    cell::RefCell,
+    gstd::msg
+};
+
+pub mod services;
+pub mod states;
+
+use states::fault_state::FaultState;
+use services::{
+    faults_service::FaultsService,
+    ping_service::PingService,
+    traffic_light_service::TrafficLightService
+};
+
+// Program that implements the Ping and TrafficLight interfaces, but it fails
+// on purpose with the fault that is set in the Faults service. It is used to
+// test the error paths of the proxy
+pub struct FaultInjectionProgram {
+    state: RefCell<FaultState>
+}
+
+#[program]
+impl FaultInjectionProgram {
+    pub fn new() -> Self {
+        Self {
+            state: RefCell::new(FaultState::new(msg::source()))
+        }
+    }
+
+    #[route("Faults")]
+    pub fn faults_svc(&self) -> FaultsService<'_> {
+        FaultsService::new(self.state.borrow_mut())
+    }
+
+    #[route("Ping")]
+    pub fn ping_svc(&self) -> PingService<'_> {
+        PingService::new(&self.state)
+    }
+
+    #[route("TrafficLight")]
+    pub fn traffic_light_svc(&self) -> TrafficLightService<'_> {
+        TrafficLightService::new(&self.state)
+    }
+}
@@ -0,0 +1,62 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefMut,
+    gstd::msg
+};
+// Import the state
+use crate::states::fault_state::{
+    Fault,
+    FaultState
+};
+
+// Service to set the fault that is injected in the Ping and TrafficLight services
+pub struct FaultsService<'a> {
+    state: RefMut<'a, FaultState>
+}
+
+#[service]
+impl<'a> FaultsService<'a> {
+    // Related function to create a new instance of the service
+    pub const fn new(state: RefMut<'a, FaultState>) -> Self {
+        Self {
+            state
+        }
+    }
+
+    // Method (command) to set the fault of the commands, only the admin (the
+    // account that deployed the program) can set it
+    pub fn set_fault(&mut self, fault: Fault) -> FaultsEvent {
+        if msg::source() != self.state.admin {
+            return FaultsEvent::Error(
+                FaultsErrors::OnlyAdminCanSetFault
+            );
+        }
+
+        self.state.fault = fault;
+
+        FaultsEvent::FaultSet(fault)
+    }
+
+    // Method (query) that returns the fault that is set
+    pub fn fault(&self) -> Fault {
+        self.state.fault
+    }
+}
+
+// Enum to set the events of the faults service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum FaultsEvent {
+    FaultSet(Fault),
+    Error(FaultsErrors)
+}
+
+// Enum to set the errors of the faults service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum FaultsErrors {
+    OnlyAdminCanSetFault
+}
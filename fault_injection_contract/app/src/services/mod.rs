@@ -0,0 +1,3 @@
+pub mod faults_service;
+pub mod ping_service;
+pub mod traffic_light_service;
@@ -0,0 +1,70 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefCell,
+    gstd::msg
+};
+// Import the state and the fault injection
+use crate::states::fault_state::{
+    inject_fault,
+    FaultState,
+    PingReply
+};
+
+// Service with the same interface of the Ping service of the ping contract
+// (without the trusted proxies, any program can call on behalf of the users)
+pub struct PingService<'a> {
+    state: &'a RefCell<FaultState>
+}
+
+#[service]
+impl<'a> PingService<'a> {
+    // Related function to create a new instance of the service
+    pub const fn new(state: &'a RefCell<FaultState>) -> Self {
+        Self {
+            state
+        }
+    }
+
+    pub async fn ping(&mut self) -> PingReply {
+        self.save_call(msg::source(), PingReply::Ping, PingReply::Pong).await
+    }
+
+    pub async fn pong(&mut self) -> PingReply {
+        self.save_call(msg::source(), PingReply::Pong, PingReply::Ping).await
+    }
+
+    pub async fn ping_on_behalf_of(&mut self, user: ActorId) -> PingReply {
+        self.save_call(user, PingReply::Ping, PingReply::Pong).await
+    }
+
+    pub async fn pong_on_behalf_of(&mut self, user: ActorId) -> PingReply {
+        self.save_call(user, PingReply::Pong, PingReply::Ping).await
+    }
+
+    // Method (query) that returns all the calls that were stored
+    pub fn all_calls(&self) -> Vec<(ActorId, PingReply)> {
+        self.state.borrow().ping_calls.clone()
+    }
+
+    // Method (query) that returns the last call that was stored
+    pub fn last_who_call(&self) -> (ActorId, PingReply) {
+        self.state
+            .borrow()
+            .ping_calls
+            .last()
+            .cloned()
+            .unwrap_or((ActorId::zero(), PingReply::Ping))
+    }
+
+    // Helper method to inject the fault and store the call of the user
+    async fn save_call(&mut self, user: ActorId, call: PingReply, reply: PingReply) -> PingReply {
+        if inject_fault(self.state).await {
+            return PingReply::Malformed;
+        }
+
+        self.state.borrow_mut().ping_calls.push((user, call));
+
+        reply
+    }
+}
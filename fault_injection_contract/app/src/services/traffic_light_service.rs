@@ -0,0 +1,93 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefCell,
+    gstd::msg
+};
+// Import the state and the fault injection
+use crate::states::fault_state::{
+    inject_fault,
+    FaultState,
+    TrafficLightReply
+};
+
+// Service with the same interface of the TrafficLight service of the traffic
+// light contract (without the trusted proxies, any program can call on behalf
+// of the users)
+pub struct TrafficLightService<'a> {
+    state: &'a RefCell<FaultState>
+}
+
+#[service]
+impl<'a> TrafficLightService<'a> {
+    // Related function to create a new instance of the service
+    pub const fn new(state: &'a RefCell<FaultState>) -> Self {
+        Self {
+            state
+        }
+    }
+
+    pub async fn green(&mut self) -> TrafficLightReply {
+        self.set_light(msg::source(), TrafficLightReply::Green).await
+    }
+
+    pub async fn yellow(&mut self) -> TrafficLightReply {
+        self.set_light(msg::source(), TrafficLightReply::Yellow).await
+    }
+
+    pub async fn red(&mut self) -> TrafficLightReply {
+        self.set_light(msg::source(), TrafficLightReply::Red).await
+    }
+
+    pub async fn green_on_behalf_of(&mut self, user: ActorId) -> TrafficLightReply {
+        self.set_light(user, TrafficLightReply::Green).await
+    }
+
+    pub async fn yellow_on_behalf_of(&mut self, user: ActorId) -> TrafficLightReply {
+        self.set_light(user, TrafficLightReply::Yellow).await
+    }
+
+    pub async fn red_on_behalf_of(&mut self, user: ActorId) -> TrafficLightReply {
+        self.set_light(user, TrafficLightReply::Red).await
+    }
+
+    // Method (query) that returns the current light and the last light of each user
+    pub fn traffic_light(&self) -> IoTrafficLightState {
+        let state = self.state.borrow();
+
+        IoTrafficLightState {
+            current_light: state.current_light.clone(),
+            all_users: state.light_users.clone()
+        }
+    }
+
+    // Helper method to inject the fault and store the light set by the user
+    async fn set_light(&mut self, user: ActorId, light: TrafficLightReply) -> TrafficLightReply {
+        if inject_fault(self.state).await {
+            return TrafficLightReply::Malformed;
+        }
+
+        let light_name = match light {
+            TrafficLightReply::Green => "Green",
+            TrafficLightReply::Yellow => "Yellow",
+            _ => "Red"
+        };
+
+        let mut state = self.state.borrow_mut();
+
+        state.current_light = light_name.to_string();
+        state.light_users.retain(|(light_user, _)| *light_user != user);
+        state.light_users.push((user, light_name.to_string()));
+
+        light
+    }
+}
+
+// Struct with the same encoding of the state of the traffic light contract
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct IoTrafficLightState {
+    pub current_light: String,
+    pub all_users: Vec<(ActorId, String)>
+}
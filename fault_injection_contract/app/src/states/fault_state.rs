@@ -0,0 +1,94 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefCell,
+    gstd::exec
+};
+
+// Enum to set the fault that is injected in the commands of the Ping and
+// TrafficLight services:
+// - None: the commands work as in the real contracts.
+// - Panic: the commands panic.
+// - OutOfGas: the commands loop until they run out of gas.
+// - Delay: the commands reply after the given number of blocks.
+// - MalformedReply: the commands reply with a variant that the clients of the
+//   real contracts can't decode.
+#[derive(PartialEq, Clone, Copy, Debug, Default, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Fault {
+    #[default]
+    None,
+    Panic,
+    OutOfGas,
+    Delay(u32),
+    MalformedReply
+}
+
+// Set the state of the program, it stores the fault and the calls of the
+// Ping and TrafficLight services (with the same data as the real contracts)
+pub struct FaultState {
+    pub admin: ActorId,
+    pub fault: Fault,
+    pub ping_calls: Vec<(ActorId, PingReply)>,
+    pub current_light: String,
+    pub light_users: Vec<(ActorId, String)>
+}
+
+impl FaultState {
+    // Related function to create a new instance of FaultState
+    pub fn new(admin: ActorId) -> Self {
+        Self {
+            admin,
+            fault: Fault::None,
+            ping_calls: Vec::new(),
+            current_light: String::new(),
+            light_users: Vec::new()
+        }
+    }
+}
+
+// Enum with the replies of the Ping service, the first variants are the same
+// as the PingEnum of the ping contract, "Malformed" can't be decoded by its clients
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PingReply {
+    Ping,
+    Pong,
+    Malformed
+}
+
+// Enum with the replies of the TrafficLight service, the first variants are the same
+// as the TrafficLightEvent of the traffic light contract, "Malformed" can't be
+// decoded by its clients
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightReply {
+    Green,
+    Yellow,
+    Red,
+    Malformed
+}
+
+// Helper function to inject the fault in a command, it returns true if the
+// command has to reply with a malformed reply. The state is not borrowed while
+// the command waits, so other messages can be handled
+pub async fn inject_fault(state: &RefCell<FaultState>) -> bool {
+    let fault = state.borrow().fault;
+
+    match fault {
+        Fault::None => false,
+        Fault::Panic => panic!("Fault injected: panic"),
+        Fault::OutOfGas => loop {
+            // Each syscall burns gas, so the message runs out of gas
+            let _ = exec::gas_available();
+        },
+        Fault::Delay(blocks) => {
+            exec::sleep_for(blocks).await;
+            false
+        },
+        Fault::MalformedReply => true
+    }
+}
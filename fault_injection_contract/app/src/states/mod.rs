@@ -0,0 +1,2 @@
+// Module to export the state with the fault and the calls of the program
+pub mod fault_state;
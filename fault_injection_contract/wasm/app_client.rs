@@ -0,0 +1,482 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct AppFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> AppFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::AppFactory for AppFactory<R> {
+    type Args = R::Args;
+    fn new(&self) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, app_factory::io::New>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod app_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <New as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = ();
+            type Reply = ();
+        }
+    }
+}
+pub struct Faults<R> {
+    remoting: R,
+}
+impl<R> Faults<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Faults for Faults<R> {
+    type Args = R::Args;
+    fn set_fault(&mut self, fault: Fault) -> impl Call<Output = FaultsEvent, Args = R::Args> {
+        RemotingAction::<_, faults::io::SetFault>::new(self.remoting.clone(), fault)
+    }
+    fn fault(&self) -> impl Query<Output = Fault, Args = R::Args> {
+        RemotingAction::<_, faults::io::Fault>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod faults {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct SetFault(());
+        impl SetFault {
+            #[allow(dead_code)]
+            pub fn encode_call(fault: super::Fault) -> Vec<u8> {
+                <SetFault as ActionIo>::encode_call(&fault)
+            }
+        }
+        impl ActionIo for SetFault {
+            const ROUTE: &'static [u8] = &[
+                24, 70, 97, 117, 108, 116, 115, 32, 83, 101, 116, 70, 97, 117, 108, 116,
+            ];
+            type Params = super::Fault;
+            type Reply = super::FaultsEvent;
+        }
+        pub struct Fault(());
+        impl Fault {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Fault as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Fault {
+            const ROUTE: &'static [u8] =
+                &[24, 70, 97, 117, 108, 116, 115, 20, 70, 97, 117, 108, 116];
+            type Params = ();
+            type Reply = super::Fault;
+        }
+    }
+}
+pub struct Ping<R> {
+    remoting: R,
+}
+impl<R> Ping<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Ping for Ping<R> {
+    type Args = R::Args;
+    fn ping(&mut self) -> impl Call<Output = PingReply, Args = R::Args> {
+        RemotingAction::<_, ping::io::Ping>::new(self.remoting.clone(), ())
+    }
+    fn ping_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = PingReply, Args = R::Args> {
+        RemotingAction::<_, ping::io::PingOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn pong(&mut self) -> impl Call<Output = PingReply, Args = R::Args> {
+        RemotingAction::<_, ping::io::Pong>::new(self.remoting.clone(), ())
+    }
+    fn pong_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = PingReply, Args = R::Args> {
+        RemotingAction::<_, ping::io::PongOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn all_calls(&self) -> impl Query<Output = Vec<(ActorId, PingReply)>, Args = R::Args> {
+        RemotingAction::<_, ping::io::AllCalls>::new(self.remoting.clone(), ())
+    }
+    fn last_who_call(&self) -> impl Query<Output = (ActorId, PingReply), Args = R::Args> {
+        RemotingAction::<_, ping::io::LastWhoCall>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod ping {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Ping(());
+        impl Ping {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Ping as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Ping {
+            const ROUTE: &'static [u8] = &[16, 80, 105, 110, 103, 16, 80, 105, 110, 103];
+            type Params = ();
+            type Reply = super::PingReply;
+        }
+        pub struct PingOnBehalfOf(());
+        impl PingOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <PingOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for PingOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 56, 80, 105, 110, 103, 79, 110, 66, 101, 104, 97, 108, 102,
+                79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingReply;
+        }
+        pub struct Pong(());
+        impl Pong {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Pong as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Pong {
+            const ROUTE: &'static [u8] = &[16, 80, 105, 110, 103, 16, 80, 111, 110, 103];
+            type Params = ();
+            type Reply = super::PingReply;
+        }
+        pub struct PongOnBehalfOf(());
+        impl PongOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <PongOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for PongOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 56, 80, 111, 110, 103, 79, 110, 66, 101, 104, 97, 108, 102,
+                79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingReply;
+        }
+        pub struct AllCalls(());
+        impl AllCalls {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AllCalls as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AllCalls {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 32, 65, 108, 108, 67, 97, 108, 108, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<(ActorId, super::PingReply)>;
+        }
+        pub struct LastWhoCall(());
+        impl LastWhoCall {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <LastWhoCall as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for LastWhoCall {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 44, 76, 97, 115, 116, 87, 104, 111, 67, 97, 108, 108,
+            ];
+            type Params = ();
+            type Reply = (ActorId, super::PingReply);
+        }
+    }
+}
+pub struct TrafficLight<R> {
+    remoting: R,
+}
+impl<R> TrafficLight<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TrafficLight for TrafficLight<R> {
+    type Args = R::Args;
+    fn green(&mut self) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::Green>::new(self.remoting.clone(), ())
+    }
+    fn green_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::GreenOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn red(&mut self) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::Red>::new(self.remoting.clone(), ())
+    }
+    fn red_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::RedOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn yellow(&mut self) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::Yellow>::new(self.remoting.clone(), ())
+    }
+    fn yellow_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightReply, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::YellowOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn traffic_light(&self) -> impl Query<Output = IoTrafficLightState, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::TrafficLight>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod traffic_light {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Green(());
+        impl Green {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Green as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Green {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 20, 71, 114, 101, 101,
+                110,
+            ];
+            type Params = ();
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct GreenOnBehalfOf(());
+        impl GreenOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <GreenOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for GreenOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 60, 71, 114, 101, 101,
+                110, 79, 110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct Red(());
+        impl Red {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Red as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Red {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 12, 82, 101, 100,
+            ];
+            type Params = ();
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct RedOnBehalfOf(());
+        impl RedOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <RedOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for RedOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 52, 82, 101, 100, 79,
+                110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct Yellow(());
+        impl Yellow {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Yellow as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Yellow {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 24, 89, 101, 108, 108,
+                111, 119,
+            ];
+            type Params = ();
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct YellowOnBehalfOf(());
+        impl YellowOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <YellowOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for YellowOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 64, 89, 101, 108, 108,
+                111, 119, 79, 110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightReply;
+        }
+        pub struct TrafficLight(());
+        impl TrafficLight {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TrafficLight as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TrafficLight {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 48, 84, 114, 97, 102,
+                102, 105, 99, 76, 105, 103, 104, 116,
+            ];
+            type Params = ();
+            type Reply = super::IoTrafficLightState;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Fault {
+    None,
+    Panic,
+    OutOfGas,
+    Delay(u32),
+    MalformedReply,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum FaultsEvent {
+    FaultSet(Fault),
+    Error(FaultsErrors),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum FaultsErrors {
+    OnlyAdminCanSetFault,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PingReply {
+    Ping,
+    Pong,
+    Malformed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightReply {
+    Green,
+    Yellow,
+    Red,
+    Malformed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct IoTrafficLightState {
+    pub current_light: String,
+    pub all_users: Vec<(ActorId, String)>,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait AppFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Faults {
+        type Args;
+        fn set_fault(&mut self, fault: Fault)
+            -> impl Call<Output = FaultsEvent, Args = Self::Args>;
+        fn fault(&self) -> impl Query<Output = Fault, Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Ping {
+        type Args;
+        fn ping(&mut self) -> impl Call<Output = PingReply, Args = Self::Args>;
+        fn ping_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = PingReply, Args = Self::Args>;
+        fn pong(&mut self) -> impl Call<Output = PingReply, Args = Self::Args>;
+        fn pong_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = PingReply, Args = Self::Args>;
+        fn all_calls(&self) -> impl Query<Output = Vec<(ActorId, PingReply)>, Args = Self::Args>;
+        fn last_who_call(&self) -> impl Query<Output = (ActorId, PingReply), Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait TrafficLight {
+        type Args;
+        fn green(&mut self) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn green_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn red(&mut self) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn red_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn yellow(&mut self) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn yellow_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightReply, Args = Self::Args>;
+        fn traffic_light(&self) -> impl Query<Output = IoTrafficLightState, Args = Self::Args>;
+    }
+}
@@ -0,0 +1,180 @@
+// Gas report of the routes of the contracts, it is written by the gas-bench
+// binary as JSON (to be saved as a baseline) and as a markdown table, and it is
+// compared with a baseline to find the routes that burn more gas than before.
+use serde::{
+    Deserialize,
+    Serialize
+};
+use std::{
+    fmt,
+    fs,
+    path::Path
+};
+
+// Default increase (in percent) of the gas of a route that is a regression
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 5.0;
+
+// Gas burned by a route of a contract, the route is the service and the
+// method ("Ping/Ping"), or the constructor for the upload of the program
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct RouteGas {
+    pub contract: String,
+    pub route: String,
+    pub gas: u64
+}
+
+// Report with the gas burned by each route, in the order they were run
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GasReport {
+    pub routes: Vec<RouteGas>
+}
+
+// Route that burns more gas than in the baseline (above the threshold)
+#[derive(PartialEq, Clone, Debug)]
+pub struct Regression {
+    pub contract: String,
+    pub route: String,
+    pub baseline: u64,
+    pub current: u64
+}
+
+impl Regression {
+    // Returns the increase of the gas in percent
+    pub fn increase_percent(&self) -> f64 {
+        percent_change(self.baseline, self.current)
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} -> {} gas (+{:.2}%)",
+            self.contract,
+            self.route,
+            self.baseline,
+            self.current,
+            self.increase_percent()
+        )
+    }
+}
+
+impl GasReport {
+    // Method to add the gas burned by a route
+    pub fn record(&mut self, contract: &str, route: &str, gas: u64) {
+        self.routes.push(RouteGas {
+            contract: contract.to_string(),
+            route: route.to_string(),
+            gas
+        });
+    }
+
+    // Returns the gas of a route, if it is in the report
+    pub fn gas(&self, contract: &str, route: &str) -> Option<u64> {
+        self.routes
+            .iter()
+            .find(|route_gas| route_gas.contract == contract && route_gas.route == route)
+            .map(|route_gas| route_gas.gas)
+    }
+
+    // Reads a report that was saved as JSON
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|error| format!("Can't read {}: {error}", path.display()))?;
+
+        serde_json::from_str(&json)
+            .map_err(|error| format!("Can't parse {}: {error}", path.display()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("The report is always serializable")
+    }
+
+    // Returns the report as a markdown table, with the gas of the baseline and
+    // the change of each route if a baseline is given
+    pub fn to_markdown(&self, baseline: Option<&GasReport>) -> String {
+        let mut table = String::new();
+
+        match baseline {
+            Some(_) => {
+                table.push_str("| Contract | Route | Gas | Baseline | Change |\n");
+                table.push_str("|---|---|---:|---:|---:|\n");
+            },
+            None => {
+                table.push_str("| Contract | Route | Gas |\n");
+                table.push_str("|---|---|---:|\n");
+            }
+        }
+
+        for route_gas in &self.routes {
+            table.push_str(&format!("| {} | {} | {} |", route_gas.contract, route_gas.route, route_gas.gas));
+
+            if let Some(baseline) = baseline {
+                match baseline.gas(&route_gas.contract, &route_gas.route) {
+                    Some(baseline_gas) => table.push_str(&format!(
+                        " {baseline_gas} | {:+.2}% |",
+                        percent_change(baseline_gas, route_gas.gas)
+                    )),
+                    None => table.push_str(" - | new |")
+                }
+            }
+
+            table.push('\n');
+        }
+
+        if let Some(baseline) = baseline {
+            for route_gas in self.missing_routes(baseline) {
+                table.push_str(&format!(
+                    "| {} | {} | - | {} | removed |\n",
+                    route_gas.contract,
+                    route_gas.route,
+                    route_gas.gas
+                ));
+            }
+        }
+
+        table
+    }
+
+    // Returns the routes that burn more gas than in the baseline, the gas has to
+    // increase more than "threshold_percent". The routes that are not in the
+    // baseline are not regressions.
+    pub fn regressions(&self, baseline: &GasReport, threshold_percent: f64) -> Vec<Regression> {
+        self.routes
+            .iter()
+            .filter_map(|route_gas| {
+                let baseline_gas = baseline.gas(&route_gas.contract, &route_gas.route)?;
+
+                if percent_change(baseline_gas, route_gas.gas) <= threshold_percent {
+                    return None;
+                }
+
+                Some(Regression {
+                    contract: route_gas.contract.clone(),
+                    route: route_gas.route.clone(),
+                    baseline: baseline_gas,
+                    current: route_gas.gas
+                })
+            })
+            .collect()
+    }
+
+    // Returns the routes of the baseline that are not in the report, so a route
+    // that is no longer measured is reported instead of being skipped
+    pub fn missing_routes(&self, baseline: &GasReport) -> Vec<RouteGas> {
+        baseline.routes
+            .iter()
+            .filter(|route_gas| self.gas(&route_gas.contract, &route_gas.route).is_none())
+            .cloned()
+            .collect()
+    }
+}
+
+// Change from the baseline gas to the current gas, in percent
+pub fn percent_change(baseline: u64, current: u64) -> f64 {
+    if baseline == 0 {
+        return if current == 0 { 0.0 } else { f64::INFINITY };
+    }
+
+    (current as f64 - baseline as f64) * 100.0 / baseline as f64
+}
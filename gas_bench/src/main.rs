@@ -0,0 +1,124 @@
+// Command line tool to measure the gas burned by the routes of the contracts:
+//
+//     gas-bench [--out <dir>] [--baseline <report.json>] [--threshold <percent>]
+//
+// It runs each route in gtest and writes the report to "<dir>/gas_report.json"
+// and "<dir>/gas_report.md" (the current directory by default). If a baseline is
+// given, it prints the routes whose gas increased more than the threshold (5% by
+// default) and the routes of the baseline that were not run, and exits with an
+// error if there are any.
+mod runner;
+
+use gas_bench::{
+    GasReport,
+    DEFAULT_THRESHOLD_PERCENT
+};
+use std::{
+    env,
+    fs,
+    path::PathBuf,
+    process::ExitCode
+};
+
+const USAGE: &str = "Usage: gas-bench [--out <dir>] [--baseline <report.json>] [--threshold <percent>]";
+
+// Options of the command line
+struct Options {
+    out: PathBuf,
+    baseline: Option<PathBuf>,
+    threshold_percent: f64
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(env::args().skip(1).collect()) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    // The baseline is read first, so a wrong path fails before the routes are run
+    let baseline = match options.baseline.as_deref().map(GasReport::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = runner::run();
+
+    let json_path = options.out.join("gas_report.json");
+    let markdown_path = options.out.join("gas_report.md");
+    let written = fs::create_dir_all(&options.out)
+        .and_then(|_| fs::write(&json_path, report.to_json()))
+        .and_then(|_| fs::write(&markdown_path, report.to_markdown(baseline.as_ref())));
+
+    if let Err(error) = written {
+        eprintln!("Can't write the report in {}: {error}", options.out.display());
+        return ExitCode::FAILURE;
+    }
+
+    println!("Report written to {} and {}", json_path.display(), markdown_path.display());
+
+    let Some(baseline) = baseline else {
+        return ExitCode::SUCCESS;
+    };
+
+    let regressions = report.regressions(&baseline, options.threshold_percent);
+    let missing_routes = report.missing_routes(&baseline);
+
+    if regressions.is_empty() && missing_routes.is_empty() {
+        println!("No gas regressions above {}%", options.threshold_percent);
+        return ExitCode::SUCCESS;
+    }
+
+    if !regressions.is_empty() {
+        eprintln!("Gas regressions above {}%:", options.threshold_percent);
+        for regression in &regressions {
+            eprintln!("  {regression}");
+        }
+    }
+
+    // The baseline has to be updated when a route is removed from the bench
+    if !missing_routes.is_empty() {
+        eprintln!("Routes of the baseline that were not run:");
+        for route_gas in &missing_routes {
+            eprintln!("  {} {}", route_gas.contract, route_gas.route);
+        }
+    }
+
+    ExitCode::FAILURE
+}
+
+fn parse_options(args: Vec<String>) -> Result<Options, String> {
+    let mut options = Options {
+        out: PathBuf::from("."),
+        baseline: None,
+        threshold_percent: DEFAULT_THRESHOLD_PERCENT
+    };
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value of {arg}"))?;
+
+        match arg.as_str() {
+            "--out" => options.out = PathBuf::from(value),
+            "--baseline" => options.baseline = Some(PathBuf::from(value)),
+            "--threshold" => {
+                options.threshold_percent = value
+                    .parse()
+                    .ok()
+                    .filter(|threshold: &f64| *threshold >= 0.0)
+                    .ok_or_else(|| format!("Invalid threshold: {value}"))?;
+            },
+            _ => return Err(format!("Unknown option: {arg}"))
+        }
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,229 @@
+// Runs the routes of the ping, traffic light and proxy contracts in gtest (a
+// local runtime) and records the gas burned by each one. The payloads are
+// encoded with the generated clients (app_client.rs) of each contract.
+use gas_bench::GasReport;
+use sails_rs::{
+    gtest::{
+        Program,
+        System
+    },
+    prelude::*
+};
+use std::path::PathBuf;
+
+// The variants of ProxyRoute are the names of the routes ("Call...")
+#[allow(dead_code, clippy::enum_variant_names)]
+mod proxy_client {
+    include!("../../proxy_contract/wasm/app_client.rs");
+}
+
+#[allow(dead_code)]
+mod ping_client {
+    include!("../../ping_pong_contract/wasm/app_client.rs");
+}
+
+#[allow(dead_code)]
+mod traffic_light_client {
+    include!("../../traffic_light_contract/wasm/app_client.rs");
+}
+
+const ADMIN: u64 = 42;
+const USER: u64 = 43;
+const INITIAL_BALANCE: u128 = 100_000_000_000_000;
+
+const PING_ID: u64 = 100;
+const TRAFFIC_LIGHT_ID: u64 = 101;
+const PROXY_ID: u64 = 102;
+
+// Struct with the gtest system and the report with the gas of the routes
+// that were run
+struct Bench<'a> {
+    system: &'a System,
+    report: GasReport
+}
+
+impl Bench<'_> {
+    // Sends the payload to the program and records the gas burned until all
+    // the messages of the route were processed (the messages to the targets
+    // and their replies are included)
+    fn run(&mut self, program: &Program, contract: &str, route: &str, from: u64, payload: Vec<u8>) {
+        let gas = self.send(program, contract, route, from, payload);
+
+        println!("{contract} {route}: {gas}");
+        self.report.record(contract, route, gas);
+    }
+
+    // Sends the payload to the program without recording it, it returns the gas
+    // burned until all the messages of the route were processed
+    fn send(&self, program: &Program, contract: &str, route: &str, from: u64, payload: Vec<u8>) -> u64 {
+        let message_id = program.send_bytes(from, payload);
+        let mut gas = 0;
+        let mut replied = false;
+
+        loop {
+            let result = self.system.run_next_block();
+
+            if result.total_processed == 0 {
+                break;
+            }
+
+            assert!(!result.failed.contains(&message_id), "{contract} {route} failed");
+
+            gas += gas_units(result.gas_allowance_spent);
+            replied |= result.log
+                .iter()
+                .any(|log| log.reply_to() == Some(message_id));
+        }
+
+        assert!(replied, "{contract} {route} did not reply");
+
+        gas
+    }
+}
+
+// Runs all the routes and returns the report
+pub fn run() -> GasReport {
+    let system = System::new();
+    system.mint_to(ADMIN, INITIAL_BALANCE);
+    system.mint_to(USER, INITIAL_BALANCE);
+
+    let ping = Program::from_binary_with_id(&system, PING_ID, contract_wasm("ping_pong_contract"));
+    let traffic_light = Program::from_binary_with_id(&system, TRAFFIC_LIGHT_ID, contract_wasm("traffic_light_contract"));
+    let proxy = Program::from_binary_with_id(&system, PROXY_ID, contract_wasm("proxy_contract"));
+
+    let mut bench = Bench {
+        system: &system,
+        report: GasReport::default()
+    };
+
+    let proxy_id = ActorId::from(PROXY_ID);
+
+    // Upload of the programs (the constructors)
+    bench.run(&ping, "ping", "New", ADMIN, ping_client::app_factory::io::New::encode_call());
+    bench.run(&traffic_light, "traffic_light", "New", ADMIN, traffic_light_client::app_factory::io::New::encode_call());
+    bench.run(
+        &proxy,
+        "proxy",
+        "NewWithContractsId",
+        ADMIN,
+        proxy_client::app_factory::io::NewWithContractsId::encode_call(PING_ID.into(), TRAFFIC_LIGHT_ID.into())
+    );
+
+    // Ping contract
+    bench.run(&ping, "ping", "Ping/AddTrustedProxy", ADMIN, ping_client::ping::io::AddTrustedProxy::encode_call(proxy_id));
+    bench.run(&ping, "ping", "Ping/Ping", USER, ping_client::ping::io::Ping::encode_call());
+    bench.run(&ping, "ping", "Ping/Pong", USER, ping_client::ping::io::Pong::encode_call());
+    bench.run(&ping, "ping", "Ping/AllCalls", USER, ping_client::ping::io::AllCalls::encode_call());
+    bench.run(&ping, "ping", "Ping/LastWhoCall", USER, ping_client::ping::io::LastWhoCall::encode_call());
+    bench.run(&ping, "ping", "Ping/TrustedProxies", USER, ping_client::ping::io::TrustedProxies::encode_call());
+    bench.run(&ping, "ping", "Ping/Info", USER, ping_client::ping::io::Info::encode_call());
+
+    // Traffic light contract
+    bench.run(
+        &traffic_light,
+        "traffic_light",
+        "TrafficLight/AddTrustedProxy",
+        ADMIN,
+        traffic_light_client::traffic_light::io::AddTrustedProxy::encode_call(proxy_id)
+    );
+    bench.run(&traffic_light, "traffic_light", "TrafficLight/Green", USER, traffic_light_client::traffic_light::io::Green::encode_call());
+    bench.run(&traffic_light, "traffic_light", "TrafficLight/Yellow", USER, traffic_light_client::traffic_light::io::Yellow::encode_call());
+    bench.run(&traffic_light, "traffic_light", "TrafficLight/Red", USER, traffic_light_client::traffic_light::io::Red::encode_call());
+    bench.run(
+        &traffic_light,
+        "traffic_light",
+        "TrafficLight/TrafficLight",
+        USER,
+        traffic_light_client::traffic_light::io::TrafficLight::encode_call()
+    );
+    bench.run(
+        &traffic_light,
+        "traffic_light",
+        "TrafficLight/TrustedProxies",
+        USER,
+        traffic_light_client::traffic_light::io::TrustedProxies::encode_call()
+    );
+    bench.run(&traffic_light, "traffic_light", "TrafficLight/Info", USER, traffic_light_client::traffic_light::io::Info::encode_call());
+
+    // The targets trust the proxy, so it sends the commands on behalf of the users
+    // (the route is recorded once, with the ping contract)
+    bench.run(
+        &proxy,
+        "proxy",
+        "Proxy/SetOnBehalfOf",
+        ADMIN,
+        proxy_client::proxy::io::SetOnBehalfOf::encode_call(proxy_client::ProxyTarget::PingContract, true)
+    );
+    bench.send(
+        &proxy,
+        "proxy",
+        "Proxy/SetOnBehalfOf",
+        ADMIN,
+        proxy_client::proxy::io::SetOnBehalfOf::encode_call(proxy_client::ProxyTarget::TrafficLightContract, true)
+    );
+
+    // Proxy contract, the callers send the messages to the targets
+    bench.run(&proxy, "proxy", "PingCaller/CallPing", USER, proxy_client::ping_caller::io::CallPing::encode_call());
+    bench.run(&proxy, "proxy", "PingCaller/CallPong", USER, proxy_client::ping_caller::io::CallPong::encode_call());
+    bench.run(&proxy, "proxy", "PingCaller/CallAllCalls", USER, proxy_client::ping_caller::io::CallAllCalls::encode_call());
+    bench.run(&proxy, "proxy", "PingCaller/CallLastWhoCall", USER, proxy_client::ping_caller::io::CallLastWhoCall::encode_call());
+    bench.run(
+        &proxy,
+        "proxy",
+        "TrafficLightCaller/CallGreen",
+        USER,
+        proxy_client::traffic_light_caller::io::CallGreen::encode_call()
+    );
+    bench.run(
+        &proxy,
+        "proxy",
+        "TrafficLightCaller/CallYellow",
+        USER,
+        proxy_client::traffic_light_caller::io::CallYellow::encode_call()
+    );
+    bench.run(
+        &proxy,
+        "proxy",
+        "TrafficLightCaller/CallRed",
+        USER,
+        proxy_client::traffic_light_caller::io::CallRed::encode_call()
+    );
+    bench.run(
+        &proxy,
+        "proxy",
+        "TrafficLightCaller/CallTrafficLightState",
+        USER,
+        proxy_client::traffic_light_caller::io::CallTrafficLightState::encode_call()
+    );
+
+    // Proxy contract, admin routes and queries
+    bench.run(
+        &proxy,
+        "proxy",
+        "Proxy/ChangeContractId",
+        ADMIN,
+        proxy_client::proxy::io::ChangeContractId::encode_call(proxy_client::ProxyTarget::PingContract, PING_ID.into())
+    );
+    bench.run(&proxy, "proxy", "Proxy/AddAdmin", ADMIN, proxy_client::proxy::io::AddAdmin::encode_call(USER.into()));
+    bench.run(&proxy, "proxy", "Proxy/ContractsId", USER, proxy_client::proxy::io::ContractsId::encode_call());
+    bench.run(&proxy, "proxy", "Proxy/Metrics", USER, proxy_client::proxy::io::Metrics::encode_call());
+    bench.run(&proxy, "proxy", "Proxy/Info", USER, proxy_client::proxy::io::Info::encode_call());
+    bench.run(&proxy, "proxy", "Credits/Balance", USER, proxy_client::credits::io::Balance::encode_call(USER.into()));
+
+    bench.report
+}
+
+// Returns the optimized wasm of a contract (see contract_wasm)
+fn contract_wasm(contract: &str) -> Vec<u8> {
+    let contract_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(contract);
+
+    contract_wasm::build(&contract_dir).unwrap_or_else(|error| std::panic!("{error}"))
+}
+
+// Returns the units of the gas of gtest, its value is private (gtest 1.7 only
+// exposes it through Display) and gtest::Gas is not re-exported by sails-rs
+fn gas_units(gas: impl std::fmt::Display) -> u64 {
+    gas.to_string()
+        .parse()
+        .expect("The gas of gtest is displayed as an integer")
+}
@@ -0,0 +1,77 @@
+// Tests of the comparison of a gas report with a baseline
+use gas_bench::{
+    percent_change,
+    GasReport,
+    Regression,
+    RouteGas
+};
+
+fn report(routes: &[(&str, u64)]) -> GasReport {
+    let mut report = GasReport::default();
+
+    for (route, gas) in routes {
+        report.record("proxy", route, *gas);
+    }
+
+    report
+}
+
+#[test]
+fn percent_change_from_the_baseline() {
+    assert_eq!(percent_change(100, 105), 5.0);
+    assert_eq!(percent_change(200, 100), -50.0);
+    assert_eq!(percent_change(100, 100), 0.0);
+    assert_eq!(percent_change(0, 0), 0.0);
+    assert_eq!(percent_change(0, 1), f64::INFINITY);
+}
+
+#[test]
+fn only_increases_above_the_threshold_are_regressions() {
+    let baseline = report(&[("Ping", 1_000), ("Pong", 1_000), ("Green", 1_000), ("Red", 0)]);
+    let current = report(&[("Ping", 1_050), ("Pong", 1_051), ("Green", 900), ("Red", 10), ("Yellow", 5_000)]);
+
+    assert_eq!(current.regressions(&baseline, 5.0), vec![
+        Regression {
+            contract: "proxy".to_string(),
+            route: "Pong".to_string(),
+            baseline: 1_000,
+            current: 1_051
+        },
+        Regression {
+            contract: "proxy".to_string(),
+            route: "Red".to_string(),
+            baseline: 0,
+            current: 10
+        }
+    ]);
+    assert_eq!(current.regressions(&baseline, 0.0).len(), 3);
+}
+
+// The same route in other contract is a different route
+#[test]
+fn routes_are_compared_by_contract_and_route() {
+    let baseline = report(&[("Ping", 1_000)]);
+    let mut current = GasReport::default();
+    current.record("ping", "Ping", 2_000);
+
+    assert!(current.regressions(&baseline, 5.0).is_empty());
+    assert_eq!(current.missing_routes(&baseline), vec![RouteGas {
+        contract: "proxy".to_string(),
+        route: "Ping".to_string(),
+        gas: 1_000
+    }]);
+}
+
+#[test]
+fn routes_of_the_baseline_that_were_not_run_are_reported() {
+    let baseline = report(&[("Ping", 1_000), ("Pong", 1_000)]);
+    let current = report(&[("Ping", 1_000), ("Yellow", 1_000)]);
+
+    let missing_routes = current.missing_routes(&baseline);
+    assert_eq!(missing_routes.len(), 1);
+    assert_eq!(missing_routes[0].route, "Pong");
+
+    let markdown = current.to_markdown(Some(&baseline));
+    assert!(markdown.contains("| proxy | Yellow | 1000 | - | new |"));
+    assert!(markdown.contains("| proxy | Pong | - | 1000 | removed |"));
+}
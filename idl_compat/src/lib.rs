@@ -0,0 +1,396 @@
+// IDL compatibility checker, it compares the IDL of a new build of a contract
+// with the last released one and classifies each difference as:
+// - Compatible: the clients generated from the released IDL still work (a new
+//   service, method or type, or a new enum variant at the end).
+// - Breaking: the clients generated from the released IDL stop working (a removed
+//   method, a changed argument or reply type, reordered enum variants, etc).
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs,
+    path::Path
+};
+use sails_idl_parser::ast::{
+    parse_idl,
+    CtorFunc,
+    EnumVariant,
+    FuncParam,
+    PrimitiveType,
+    Service,
+    ServiceFunc,
+    StructField,
+    TypeDecl,
+    TypeDef,
+    TypeId
+};
+
+// Struct with the declarations of an IDL that are compared, they are taken from
+// the AST of the sails IDL parser
+#[derive(Default)]
+pub struct Idl {
+    pub types: BTreeMap<String, TypeDef>,
+    pub constructors: Vec<CtorFunc>,
+    pub services: BTreeMap<String, Service>
+}
+
+// Enum to set each difference between two IDLs
+#[derive(PartialEq, Clone, Debug)]
+pub enum Change {
+    Compatible(String),
+    Breaking(String)
+}
+
+impl Change {
+    pub fn is_breaking(&self) -> bool {
+        matches!(self, Change::Breaking(_))
+    }
+
+    pub fn description(&self) -> &str {
+        match self {
+            Change::Compatible(description) | Change::Breaking(description) => description
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Compatible(description) => write!(f, "compatible: {description}"),
+            Change::Breaking(description) => write!(f, "breaking: {description}")
+        }
+    }
+}
+
+impl Idl {
+    // Related function to parse an IDL generated by sails
+    pub fn parse(idl: &str) -> Result<Self, String> {
+        let program = parse_idl(idl).map_err(|error| error.to_string())?;
+
+        Ok(Self {
+            types: program
+                .types()
+                .iter()
+                .map(|type_decl| (type_decl.name().to_string(), type_decl.def().clone()))
+                .collect(),
+            constructors: program
+                .ctor()
+                .map(|ctor| ctor.funcs().to_vec())
+                .unwrap_or_default(),
+            services: program
+                .services()
+                .iter()
+                .map(|service| (service.name().to_string(), service.clone()))
+                .collect()
+        })
+    }
+}
+
+// Compares the released IDL with the new one, it returns the changes ordered by
+// constructors, services and types
+pub fn compare(released: &Idl, new: &Idl) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    compare_constructors(&released.constructors, &new.constructors, &mut changes);
+
+    for (name, service) in &released.services {
+        match new.services.get(name) {
+            Some(new_service) => {
+                let scope = format!("service {name}");
+
+                compare_service_funcs(&scope, service.funcs(), new_service.funcs(), &mut changes);
+                compare_variants(&format!("{scope} events"), service.events(), new_service.events(), &mut changes);
+            },
+            None => changes.push(Change::Breaking(format!("service {name} removed")))
+        }
+    }
+
+    for name in new.services.keys().filter(|name| !released.services.contains_key(*name)) {
+        changes.push(Change::Compatible(format!("service {name} added")));
+    }
+
+    for (name, type_def) in &released.types {
+        match new.types.get(name) {
+            Some(new_type_def) => compare_types(name, type_def, new_type_def, &mut changes),
+            None => changes.push(Change::Breaking(format!("type {name} removed")))
+        }
+    }
+
+    for name in new.types.keys().filter(|name| !released.types.contains_key(*name)) {
+        changes.push(Change::Compatible(format!("type {name} added")));
+    }
+
+    changes
+}
+
+// Compares the IDL files and returns the changes, it returns an error with the
+// breaking changes that are not in the acknowledged file (one description per
+// line, as it is shown after "breaking: "). The released IDL is required, if it
+// is missing the check fails (it would pass without comparing anything)
+pub fn check_files(released: &Path, new: &Path, acknowledged: Option<&Path>) -> Result<Vec<Change>, String> {
+    let released_idl = fs::read_to_string(released)
+        .map_err(|error| format!("Can't read the released IDL {}: {error}", released.display()))?;
+    let new_idl = fs::read_to_string(new)
+        .map_err(|error| format!("Can't read {}: {error}", new.display()))?;
+    let acknowledged_changes = acknowledged
+        .and_then(|acknowledged| fs::read_to_string(acknowledged).ok())
+        .unwrap_or_default();
+
+    let changes = compare(
+        &Idl::parse(&released_idl).map_err(|error| format!("{}: {error}", released.display()))?,
+        &Idl::parse(&new_idl).map_err(|error| format!("{}: {error}", new.display()))?
+    );
+
+    let acknowledged_changes: Vec<&str> = acknowledged_changes
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let unacknowledged: Vec<String> = changes
+        .iter()
+        .filter(|change| change.is_breaking() && !acknowledged_changes.contains(&change.description()))
+        .map(|change| format!("  {}", change.description()))
+        .collect();
+
+    if !unacknowledged.is_empty() {
+        return Err(format!(
+            "{} has breaking changes from the released IDL {}:\n{}\n\
+            If they are intended, add them to {} (one per line).",
+            new.display(),
+            released.display(),
+            unacknowledged.join("\n"),
+            acknowledged
+                .map(|acknowledged| acknowledged.display().to_string())
+                .unwrap_or("the acknowledged file".to_string())
+        ));
+    }
+
+    Ok(changes)
+}
+
+fn compare_constructors(released: &[CtorFunc], new: &[CtorFunc], changes: &mut Vec<Change>) {
+    for func in released {
+        match new.iter().find(|new_func| new_func.name() == func.name()) {
+            Some(new_func) => compare_params("constructor", func.name(), func.params(), new_func.params(), changes),
+            None => changes.push(Change::Breaking(format!("constructor: method {} removed", func.name())))
+        }
+    }
+
+    for func in new.iter().filter(|func| !released.iter().any(|released| released.name() == func.name())) {
+        changes.push(Change::Compatible(format!("constructor: method {} added", func.name())));
+    }
+}
+
+fn compare_service_funcs(scope: &str, released: &[ServiceFunc], new: &[ServiceFunc], changes: &mut Vec<Change>) {
+    for func in released {
+        let Some(new_func) = new.iter().find(|new_func| new_func.name() == func.name()) else {
+            changes.push(Change::Breaking(format!("{scope}: method {} removed", func.name())));
+            continue;
+        };
+
+        if func.is_query() != new_func.is_query() {
+            changes.push(Change::Breaking(format!(
+                "{scope}: method {} changed from {} to {}",
+                func.name(),
+                func_kind(func),
+                func_kind(new_func)
+            )));
+        }
+
+        compare_params(scope, func.name(), func.params(), new_func.params(), changes);
+
+        if func.output() != new_func.output() {
+            changes.push(Change::Breaking(format!(
+                "{scope}: method {} reply changed from {} to {}",
+                func.name(),
+                format_type(func.output()),
+                format_type(new_func.output())
+            )));
+        }
+    }
+
+    for func in new.iter().filter(|func| !released.iter().any(|released| released.name() == func.name())) {
+        changes.push(Change::Compatible(format!("{scope}: method {} added", func.name())));
+    }
+}
+
+// The arguments are encoded by their position, so only their types must be kept
+fn compare_params(scope: &str, name: &str, released: &[FuncParam], new: &[FuncParam], changes: &mut Vec<Change>) {
+    let param_types: Vec<&TypeDecl> = released.iter().map(FuncParam::type_decl).collect();
+    let new_param_types: Vec<&TypeDecl> = new.iter().map(FuncParam::type_decl).collect();
+
+    if param_types != new_param_types {
+        changes.push(Change::Breaking(format!(
+            "{scope}: method {name} arguments changed from ({}) to ({})",
+            format_params(released),
+            format_params(new)
+        )));
+    } else if released != new {
+        changes.push(Change::Compatible(format!(
+            "{scope}: method {name} arguments renamed from ({}) to ({})",
+            format_params(released),
+            format_params(new)
+        )));
+    }
+}
+
+fn compare_types(name: &str, released: &TypeDef, new: &TypeDef, changes: &mut Vec<Change>) {
+    match (released, new) {
+        // The fields are encoded by their position, the docs are not compared
+        (TypeDef::Struct(released), TypeDef::Struct(new)) => {
+            let fields = |fields: &[StructField]| fields
+                .iter()
+                .map(|field| (field.name().map(str::to_string), field.type_decl().clone()))
+                .collect::<Vec<_>>();
+
+            if fields(released.fields()) != fields(new.fields()) {
+                changes.push(Change::Breaking(format!(
+                    "type {name} fields changed from {{ {} }} to {{ {} }}",
+                    format_fields(released.fields()),
+                    format_fields(new.fields())
+                )));
+            }
+        },
+        (TypeDef::Enum(released), TypeDef::Enum(new)) => {
+            compare_variants(&format!("type {name}"), released.variants(), new.variants(), changes);
+        },
+        _ => changes.push(Change::Breaking(format!(
+            "type {name} changed from {} to {}",
+            type_def_kind(released),
+            type_def_kind(new)
+        )))
+    }
+}
+
+// The variants are encoded by their position, so the released variants must keep
+// their position and data, new variants can only be added at the end
+fn compare_variants(scope: &str, released: &[EnumVariant], new: &[EnumVariant], changes: &mut Vec<Change>) {
+    for (index, variant) in released.iter().enumerate() {
+        let name = variant.name();
+        let Some(new_index) = new.iter().position(|new_variant| new_variant.name() == name) else {
+            changes.push(Change::Breaking(format!("{scope}: variant {name} removed")));
+            continue;
+        };
+
+        if new_index != index {
+            changes.push(Change::Breaking(format!(
+                "{scope}: variant {name} moved from position {index} to {new_index}"
+            )));
+        }
+
+        let new_data = new[new_index].type_decl();
+
+        if variant.type_decl() != new_data {
+            changes.push(Change::Breaking(format!(
+                "{scope}: variant {name} data changed from {} to {}",
+                format_variant_data(variant.type_decl()),
+                format_variant_data(new_data)
+            )));
+        }
+    }
+
+    for (index, variant) in new.iter().enumerate() {
+        let name = variant.name();
+
+        if released.iter().any(|released_variant| released_variant.name() == name) {
+            continue;
+        }
+
+        if index >= released.len() {
+            changes.push(Change::Compatible(format!("{scope}: variant {name} added at the end")));
+        } else {
+            changes.push(Change::Breaking(format!("{scope}: variant {name} inserted at position {index}")));
+        }
+    }
+}
+
+// Formats a type as it is written in the IDL
+fn format_type(type_decl: &TypeDecl) -> String {
+    match type_decl {
+        TypeDecl::Vector(item) => format!("vec {}", format_type(item)),
+        TypeDecl::Array { item, len } => format!("[{}, {len}]", format_type(item)),
+        TypeDecl::Map { key, value } => format!("map ({}, {})", format_type(key), format_type(value)),
+        TypeDecl::Optional(item) => format!("opt {}", format_type(item)),
+        TypeDecl::Result { ok, err } => format!("result ({}, {})", format_type(ok), format_type(err)),
+        TypeDecl::Id(TypeId::Primitive(primitive)) => primitive_name(*primitive).to_string(),
+        TypeDecl::Id(TypeId::UserDefined(name)) => name.clone(),
+        TypeDecl::Def(TypeDef::Struct(struct_def)) => format!("struct {{ {} }}", format_fields(struct_def.fields())),
+        TypeDecl::Def(TypeDef::Enum(enum_def)) => format!(
+            "enum {{ {} }}",
+            enum_def
+                .variants()
+                .iter()
+                .map(|variant| match variant.type_decl() {
+                    Some(data) => format!("{}: {}", variant.name(), format_type(data)),
+                    None => variant.name().to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn primitive_name(primitive: PrimitiveType) -> &'static str {
+    match primitive {
+        PrimitiveType::Null => "null",
+        PrimitiveType::Bool => "bool",
+        PrimitiveType::Char => "char",
+        PrimitiveType::Str => "str",
+        PrimitiveType::U8 => "u8",
+        PrimitiveType::U16 => "u16",
+        PrimitiveType::U32 => "u32",
+        PrimitiveType::U64 => "u64",
+        PrimitiveType::U128 => "u128",
+        PrimitiveType::I8 => "i8",
+        PrimitiveType::I16 => "i16",
+        PrimitiveType::I32 => "i32",
+        PrimitiveType::I64 => "i64",
+        PrimitiveType::I128 => "i128",
+        PrimitiveType::ActorId => "actor_id",
+        PrimitiveType::CodeId => "code_id",
+        PrimitiveType::MessageId => "message_id",
+        PrimitiveType::H256 => "h256",
+        PrimitiveType::U256 => "u256",
+        PrimitiveType::H160 => "h160",
+        PrimitiveType::NonZeroU8 => "nat8",
+        PrimitiveType::NonZeroU16 => "nat16",
+        PrimitiveType::NonZeroU32 => "nat32",
+        PrimitiveType::NonZeroU64 => "nat64",
+        PrimitiveType::NonZeroU128 => "nat128",
+        PrimitiveType::NonZeroU256 => "nat256"
+    }
+}
+
+fn format_params(params: &[FuncParam]) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}: {}", param.name(), format_type(param.type_decl())))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_fields(fields: &[StructField]) -> String {
+    fields
+        .iter()
+        .map(|field| match field.name() {
+            Some(name) => format!("{name}: {}", format_type(field.type_decl())),
+            None => format_type(field.type_decl())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_variant_data(data: Option<&TypeDecl>) -> String {
+    data.map(format_type).unwrap_or("none".to_string())
+}
+
+fn type_def_kind(type_def: &TypeDef) -> &'static str {
+    match type_def {
+        TypeDef::Struct(_) => "struct",
+        TypeDef::Enum(_) => "enum"
+    }
+}
+
+fn func_kind(func: &ServiceFunc) -> &'static str {
+    if func.is_query() { "query" } else { "command" }
+}
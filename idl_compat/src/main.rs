@@ -0,0 +1,40 @@
+// Command line tool to compare the IDL of a contract with the last released one:
+//
+//     idl-compat <released.idl> <new.idl> [acknowledged.txt]
+//
+// It prints each change as "compatible: ..." or "breaking: ...", and exits with
+// an error if there are breaking changes that are not in the acknowledged file.
+use std::{
+    env,
+    path::Path,
+    process::ExitCode
+};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("Usage: idl-compat <released.idl> <new.idl> [acknowledged.txt]");
+        return ExitCode::from(2);
+    }
+
+    let acknowledged = args.get(2).map(Path::new);
+
+    match idl_compat::check_files(Path::new(&args[0]), Path::new(&args[1]), acknowledged) {
+        Ok(changes) => {
+            for change in &changes {
+                println!("{change}");
+            }
+
+            if changes.is_empty() {
+                println!("No changes");
+            }
+
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
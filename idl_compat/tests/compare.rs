@@ -0,0 +1,162 @@
+// Tests of the classification of the changes between a released IDL and a new one
+use std::{
+    env,
+    fs,
+    path::Path
+};
+use idl_compat::{
+    check_files,
+    compare,
+    Change,
+    Idl
+};
+
+const RELEASED_IDL: &str = r#"
+type PingEnum = enum {
+  Ping,
+  Pong,
+};
+
+constructor {
+  New : ();
+};
+
+service Ping {
+  Ping : () -> PingEnum;
+  Pong : (user: actor_id) -> PingEnum;
+  query AllCalls : () -> vec PingEnum;
+};
+"#;
+
+// Returns the changes from the released IDL to the IDL with the replacements
+fn changes(replacements: &[(&str, &str)]) -> Vec<Change> {
+    let new_idl = replacements
+        .iter()
+        .fold(RELEASED_IDL.to_string(), |idl, (from, to)| {
+            assert!(idl.contains(from), "{from} is not in the IDL");
+            idl.replace(from, to)
+        });
+
+    compare(&Idl::parse(RELEASED_IDL).unwrap(), &Idl::parse(&new_idl).unwrap())
+}
+
+fn breaking(description: &str) -> Change {
+    Change::Breaking(description.to_string())
+}
+
+fn compatible(description: &str) -> Change {
+    Change::Compatible(description.to_string())
+}
+
+#[test]
+fn same_idl_has_no_changes() {
+    assert_eq!(changes(&[]), Vec::new());
+}
+
+#[test]
+fn removed_method_is_breaking() {
+    let changes = changes(&[("  Pong : (user: actor_id) -> PingEnum;\n", "")]);
+
+    assert_eq!(changes, vec![breaking("service Ping: method Pong removed")]);
+}
+
+#[test]
+fn added_method_is_compatible() {
+    let changes = changes(&[(
+        "  query AllCalls",
+        "  query CallsCount : () -> u32;\n  query AllCalls"
+    )]);
+
+    assert_eq!(changes, vec![compatible("service Ping: method CallsCount added")]);
+}
+
+#[test]
+fn reordered_variants_are_breaking() {
+    let changes = changes(&[("  Ping,\n  Pong,", "  Pong,\n  Ping,")]);
+
+    assert_eq!(changes, vec![
+        breaking("type PingEnum: variant Ping moved from position 0 to 1"),
+        breaking("type PingEnum: variant Pong moved from position 1 to 0")
+    ]);
+}
+
+#[test]
+fn variant_added_at_the_end_is_compatible() {
+    let changes = changes(&[("  Pong,\n", "  Pong,\n  Pang,\n")]);
+
+    assert_eq!(changes, vec![compatible("type PingEnum: variant Pang added at the end")]);
+}
+
+#[test]
+fn variant_inserted_before_the_end_is_breaking() {
+    let changes = changes(&[("  Ping,\n", "  Pang,\n  Ping,\n")]);
+
+    assert!(changes.contains(&breaking("type PingEnum: variant Pang inserted at position 0")));
+    assert!(changes.iter().all(Change::is_breaking));
+}
+
+#[test]
+fn changed_argument_type_is_breaking() {
+    let changes = changes(&[("Pong : (user: actor_id)", "Pong : (user: u64)")]);
+
+    assert_eq!(changes, vec![breaking(
+        "service Ping: method Pong arguments changed from (user: actor_id) to (user: u64)"
+    )]);
+}
+
+// The arguments are encoded by their position, so only the types matter
+#[test]
+fn renamed_argument_is_compatible() {
+    let changes = changes(&[("Pong : (user: actor_id)", "Pong : (actor: actor_id)")]);
+
+    assert_eq!(changes, vec![compatible(
+        "service Ping: method Pong arguments renamed from (user: actor_id) to (actor: actor_id)"
+    )]);
+}
+
+#[test]
+fn changed_reply_is_breaking() {
+    let changes = changes(&[("AllCalls : () -> vec PingEnum", "AllCalls : () -> u32")]);
+
+    assert_eq!(changes, vec![breaking(
+        "service Ping: method AllCalls reply changed from vec PingEnum to u32"
+    )]);
+}
+
+#[test]
+fn query_changed_to_command_is_breaking() {
+    let changes = changes(&[("query AllCalls", "AllCalls")]);
+
+    assert_eq!(changes, vec![breaking("service Ping: method AllCalls changed from query to command")]);
+}
+
+#[test]
+fn check_fails_without_the_released_idl() {
+    let new_idl = env::temp_dir().join("idl_compat_check_fails_without_the_released_idl.idl");
+    fs::write(&new_idl, RELEASED_IDL).unwrap();
+
+    let result = check_files(Path::new("missing/app.released.idl"), &new_idl, None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn check_fails_on_unacknowledged_breaking_changes() {
+    let dir = env::temp_dir().join("idl_compat_check_fails_on_unacknowledged_breaking_changes");
+    fs::create_dir_all(&dir).unwrap();
+
+    let released_idl = dir.join("app.released.idl");
+    let new_idl = dir.join("app.idl");
+    let acknowledged = dir.join("idl_breaking_changes.txt");
+    fs::write(&released_idl, RELEASED_IDL).unwrap();
+    fs::write(&new_idl, RELEASED_IDL.replace("  Pong : (user: actor_id) -> PingEnum;\n", "")).unwrap();
+    fs::write(&acknowledged, "# Acknowledged changes\n").unwrap();
+
+    let error = check_files(&released_idl, &new_idl, Some(&acknowledged)).unwrap_err();
+    assert!(error.contains("service Ping: method Pong removed"));
+
+    fs::write(&acknowledged, "service Ping: method Pong removed\n").unwrap();
+
+    let changes = check_files(&released_idl, &new_idl, Some(&acknowledged)).unwrap();
+    assert_eq!(changes, vec![breaking("service Ping: method Pong removed")]);
+}
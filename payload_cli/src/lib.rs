@@ -0,0 +1,687 @@
+// Encoder and decoder of the payloads of a contract, it only uses the IDL of the
+// contract (parsed with the IDL parser of sails) and the encoding of sails:
+// - Calls: the SCALE encoded names of the service and the method (only the name
+//   for constructors), followed by the SCALE encoded arguments.
+// - Replies: the names of the service and the method, followed by the SCALE
+//   encoded output of the method.
+//
+// The values are written as JSON: numbers (or strings for big numbers), strings,
+// "0x..." hex strings for ids, hashes and bytes, arrays for vectors and tuple
+// structs, objects for structs, "Variant" or {"Variant": data} for enums, null
+// or the value for options and {"Ok": value} or {"Err": value} for results.
+use parity_scale_codec::{
+    Compact,
+    Decode,
+    Encode
+};
+use sails_idl_parser::ast::{
+    self,
+    EnumDef,
+    FuncParam,
+    PrimitiveType,
+    Program,
+    StructDef,
+    TypeDecl,
+    TypeDef,
+    TypeId
+};
+use serde_json::{
+    Map,
+    Value
+};
+use std::{
+    fs,
+    path::Path
+};
+
+// Struct with the IDL of the contract that is used to encode and decode payloads
+pub struct PayloadCodec {
+    program: Program
+}
+
+// Constructor or method of a service, the constructors don't have an output
+struct Func<'a> {
+    name: &'a str,
+    params: &'a [FuncParam],
+    output: Option<&'a TypeDecl>
+}
+
+impl PayloadCodec {
+    // Related function to create the codec from the text of an IDL
+    pub fn new(idl: &str) -> Result<Self, String> {
+        Ok(Self {
+            program: parse_idl(idl)?
+        })
+    }
+
+    // Reads the IDL from a file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let idl = fs::read_to_string(path)
+            .map_err(|error| format!("Can't read {}: {error}", path.display()))?;
+
+        Self::new(&idl).map_err(|error| format!("Can't parse {}: {error}", path.display()))
+    }
+
+    // Encodes the call of a method ("Service/Method") or a constructor ("Name"),
+    // there has to be one value for each argument
+    pub fn encode_call(&self, route: &str, args: &[Value]) -> Result<Vec<u8>, String> {
+        let (service, func) = self.find_func(route)?;
+
+        if args.len() != func.params.len() {
+            return Err(format!(
+                "{route} expects {} arguments ({}), {} were given",
+                func.params.len(),
+                func.params
+                    .iter()
+                    .map(|param| param.name())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                args.len()
+            ));
+        }
+
+        let mut payload = Vec::new();
+
+        if let Some(service) = service {
+            service.encode_to(&mut payload);
+        }
+        func.name.encode_to(&mut payload);
+
+        for (param, arg) in func.params.iter().zip(args) {
+            self.encode_value(param.type_decl(), arg, &mut payload)
+                .map_err(|error| format!("Argument {}: {error}", param.name()))?;
+        }
+
+        Ok(payload)
+    }
+
+    // Decodes the payload of a call, it returns the route and the arguments
+    // (an object with the name of each argument)
+    pub fn decode_call(&self, payload: &[u8]) -> Result<(String, Value), String> {
+        let mut input = payload;
+        let (route, func) = self.decode_route(&mut input)?;
+
+        let mut args = Map::new();
+        for param in func.params {
+            let arg = self.decode_value(param.type_decl(), &mut input)
+                .map_err(|error| format!("Argument {}: {error}", param.name()))?;
+            args.insert(param.name().to_string(), arg);
+        }
+
+        check_end(input)?;
+
+        Ok((route, Value::Object(args)))
+    }
+
+    // Decodes the payload of a reply, it returns the route and the output of the
+    // method (null for constructors)
+    pub fn decode_reply(&self, payload: &[u8]) -> Result<(String, Value), String> {
+        let mut input = payload;
+        let (route, func) = self.decode_route(&mut input)?;
+        let output = match func.output {
+            Some(output) => self.decode_value(output, &mut input)?,
+            None => Value::Null
+        };
+
+        check_end(input)?;
+
+        Ok((route, output))
+    }
+
+    // Decodes a value of a type of the IDL, like the events of the services
+    // ("ProxyEvent") or an inline type ("vec actor_id")
+    pub fn decode_type(&self, type_name: &str, bytes: &[u8]) -> Result<Value, String> {
+        // The type is parsed as the output of a method, so it is written as in the IDL
+        let program = parse_idl(&format!("service Types {{ query Decode : () -> {type_name}; }};"))
+            .map_err(|error| format!("Invalid type {type_name}: {error}"))?;
+        let type_decl = program.services()[0].funcs()[0].output();
+
+        let mut input = bytes;
+        let value = self.decode_value(type_decl, &mut input)?;
+
+        check_end(input)?;
+
+        Ok(value)
+    }
+
+    // Returns the service (None for constructors) and the method of a route
+    fn find_func(&self, route: &str) -> Result<(Option<&str>, Func<'_>), String> {
+        match route.split_once('/') {
+            Some((service, method)) => {
+                let service = self.program
+                    .services()
+                    .iter()
+                    .find(|idl_service| idl_service.name() == service)
+                    .ok_or(format!("Unknown service: {service}"))?;
+                let func = service
+                    .funcs()
+                    .iter()
+                    .find(|func| func.name() == method)
+                    .ok_or(format!("Unknown method: {route}"))?;
+
+                Ok((Some(service.name()), Func {
+                    name: func.name(),
+                    params: func.params(),
+                    output: Some(func.output())
+                }))
+            },
+            None => {
+                let func = self.program
+                    .ctor()
+                    .and_then(|ctor| ctor.funcs().iter().find(|func| func.name() == route))
+                    .ok_or(format!("Unknown constructor: {route}"))?;
+
+                Ok((None, Func {
+                    name: func.name(),
+                    params: func.params(),
+                    output: None
+                }))
+            }
+        }
+    }
+
+    // Reads the route at the start of a payload, the first name is a service or
+    // a constructor
+    fn decode_route(&self, input: &mut &[u8]) -> Result<(String, Func<'_>), String> {
+        let name: String = decode(input)?;
+
+        let is_service = self.program
+            .services()
+            .iter()
+            .any(|service| service.name() == name);
+
+        let route = if is_service {
+            format!("{name}/{}", decode::<String>(input)?)
+        } else {
+            name
+        };
+
+        let (_, func) = self.find_func(&route)?;
+
+        Ok((route, func))
+    }
+
+    // Returns the declaration of a type of the IDL
+    fn type_def(&self, name: &str) -> Result<&TypeDef, String> {
+        self.program
+            .types()
+            .iter()
+            .find(|idl_type| idl_type.name() == name)
+            .map(|idl_type| idl_type.def())
+            .ok_or(format!("Unknown type: {name}"))
+    }
+
+    fn encode_value(&self, type_decl: &TypeDecl, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+        match type_decl {
+            TypeDecl::Id(TypeId::Primitive(primitive)) => encode_primitive(primitive, value, out),
+            TypeDecl::Id(TypeId::UserDefined(name)) => self.encode_def(self.type_def(name)?, value, out),
+            TypeDecl::Def(def) => self.encode_def(def, value, out),
+            TypeDecl::Optional(inner) => match value {
+                Value::Null => {
+                    out.push(0);
+                    Ok(())
+                },
+                value => {
+                    out.push(1);
+                    self.encode_value(inner, value, out)
+                }
+            },
+            TypeDecl::Vector(item) => {
+                if let Some(bytes) = hex_bytes(item, value)? {
+                    bytes.encode_to(out);
+                    return Ok(());
+                }
+
+                let items = value
+                    .as_array()
+                    .ok_or(format!("Expected an array: {value}"))?;
+
+                Compact(items.len() as u32).encode_to(out);
+                items
+                    .iter()
+                    .try_for_each(|item_value| self.encode_value(item, item_value, out))
+            },
+            TypeDecl::Array { item, len } => {
+                let len = *len as usize;
+
+                if let Some(bytes) = hex_bytes(item, value)? {
+                    if bytes.len() != len {
+                        return Err(format!("Expected {len} bytes: {value}"));
+                    }
+                    out.extend(bytes);
+                    return Ok(());
+                }
+
+                let items = value
+                    .as_array()
+                    .filter(|items| items.len() == len)
+                    .ok_or(format!("Expected an array of {len} items: {value}"))?;
+
+                items
+                    .iter()
+                    .try_for_each(|item_value| self.encode_value(item, item_value, out))
+            },
+            TypeDecl::Result { ok, err } => match single_entry(value) {
+                Some(("Ok", data)) => {
+                    out.push(0);
+                    self.encode_value(ok, data, out)
+                },
+                Some(("Err", data)) => {
+                    out.push(1);
+                    self.encode_value(err, data, out)
+                },
+                _ => Err(format!("Expected {{\"Ok\": ..}} or {{\"Err\": ..}}: {value}"))
+            },
+            TypeDecl::Map { key, value: value_type } => {
+                let entries = value
+                    .as_array()
+                    .ok_or(format!("Expected an array of [key, value] entries: {value}"))?;
+
+                Compact(entries.len() as u32).encode_to(out);
+                entries.iter().try_for_each(|entry| match entry.as_array().map(Vec::as_slice) {
+                    Some([key_value, entry_value]) => {
+                        self.encode_value(key, key_value, out)?;
+                        self.encode_value(value_type, entry_value, out)
+                    },
+                    _ => Err(format!("Expected a [key, value] entry: {entry}"))
+                })
+            },
+            #[allow(unreachable_patterns)]
+            _ => Err(format!("Unsupported type: {type_decl:?}"))
+        }
+    }
+
+    fn encode_def(&self, def: &TypeDef, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+        match def {
+            TypeDef::Struct(def) => self.encode_struct(def, value, out),
+            TypeDef::Enum(def) => self.encode_enum(def, value, out)
+        }
+    }
+
+    fn encode_struct(&self, def: &StructDef, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+        let fields = def.fields();
+
+        // Tuple structs are written as arrays
+        if fields.iter().all(|field| field.name().is_none()) {
+            let items = value
+                .as_array()
+                .filter(|items| items.len() == fields.len())
+                .ok_or(format!("Expected an array of {} items: {value}", fields.len()))?;
+
+            return fields
+                .iter()
+                .zip(items)
+                .try_for_each(|(field, item)| self.encode_value(field.type_decl(), item, out));
+        }
+
+        let object = value
+            .as_object()
+            .ok_or(format!("Expected an object: {value}"))?;
+
+        fields.iter().try_for_each(|field| {
+            let name = field.name().unwrap_or_default();
+            let field_value = object
+                .get(name)
+                .ok_or(format!("Missing field {name}: {value}"))?;
+
+            self.encode_value(field.type_decl(), field_value, out)
+                .map_err(|error| format!("Field {name}: {error}"))
+        })
+    }
+
+    fn encode_enum(&self, def: &EnumDef, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+        let variants = def.variants();
+
+        let (name, data) = match value {
+            Value::String(name) => (name.as_str(), None),
+            value => match single_entry(value) {
+                Some((name, data)) => (name, Some(data)),
+                None => return Err(format!("Expected \"Variant\" or {{\"Variant\": ..}}: {value}"))
+            }
+        };
+
+        let index = variants
+            .iter()
+            .position(|variant| variant.name() == name)
+            .ok_or(format!(
+                "Unknown variant {name}, expected one of: {}",
+                variants
+                    .iter()
+                    .map(|variant| variant.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))?;
+
+        // The variants are encoded by their position
+        out.push(index as u8);
+
+        match (variants[index].type_decl(), data) {
+            (None, None) => Ok(()),
+            (Some(data_type), Some(data)) => self.encode_value(data_type, data, out),
+            (None, Some(_)) => Err(format!("The variant {name} has no data")),
+            (Some(_), None) => Err(format!("The variant {name} needs data"))
+        }
+    }
+
+    fn decode_value(&self, type_decl: &TypeDecl, input: &mut &[u8]) -> Result<Value, String> {
+        match type_decl {
+            TypeDecl::Id(TypeId::Primitive(primitive)) => decode_primitive(primitive, input),
+            TypeDecl::Id(TypeId::UserDefined(name)) => self.decode_def(self.type_def(name)?, input),
+            TypeDecl::Def(def) => self.decode_def(def, input),
+            TypeDecl::Optional(inner) => match decode::<u8>(input)? {
+                0 => Ok(Value::Null),
+                1 => self.decode_value(inner, input),
+                tag => Err(format!("Invalid option tag: {tag}"))
+            },
+            TypeDecl::Vector(item) => {
+                if is_u8(item) {
+                    return Ok(Value::String(hex_encode(&decode::<Vec<u8>>(input)?)));
+                }
+
+                let len = decode::<Compact<u32>>(input)?.0;
+
+                (0..len)
+                    .map(|_| self.decode_value(item, input))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Value::Array)
+            },
+            TypeDecl::Array { item, len } => {
+                if is_u8(item) {
+                    return Ok(Value::String(hex_encode(&take(input, *len as usize)?)));
+                }
+
+                (0..*len)
+                    .map(|_| self.decode_value(item, input))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Value::Array)
+            },
+            TypeDecl::Result { ok, err } => {
+                let (name, data_type) = match decode::<u8>(input)? {
+                    0 => ("Ok", ok),
+                    1 => ("Err", err),
+                    tag => return Err(format!("Invalid result tag: {tag}"))
+                };
+
+                let data = self.decode_value(data_type, input)?;
+
+                Ok(Value::Object(Map::from_iter([(name.to_string(), data)])))
+            },
+            TypeDecl::Map { key, value } => {
+                let len = decode::<Compact<u32>>(input)?.0;
+
+                (0..len)
+                    .map(|_| {
+                        let key_value = self.decode_value(key, input)?;
+                        let entry_value = self.decode_value(value, input)?;
+                        Ok(Value::Array(vec![key_value, entry_value]))
+                    })
+                    .collect::<Result<Vec<_>, String>>()
+                    .map(Value::Array)
+            },
+            #[allow(unreachable_patterns)]
+            _ => Err(format!("Unsupported type: {type_decl:?}"))
+        }
+    }
+
+    fn decode_def(&self, def: &TypeDef, input: &mut &[u8]) -> Result<Value, String> {
+        match def {
+            TypeDef::Struct(def) => {
+                let fields = def.fields();
+
+                if fields.iter().all(|field| field.name().is_none()) {
+                    return fields
+                        .iter()
+                        .map(|field| self.decode_value(field.type_decl(), input))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(Value::Array);
+                }
+
+                let mut object = Map::new();
+                for field in fields {
+                    let name = field.name().unwrap_or_default();
+                    let field_value = self.decode_value(field.type_decl(), input)
+                        .map_err(|error| format!("Field {name}: {error}"))?;
+                    object.insert(name.to_string(), field_value);
+                }
+
+                Ok(Value::Object(object))
+            },
+            TypeDef::Enum(def) => {
+                let index = decode::<u8>(input)?;
+                let variant = def
+                    .variants()
+                    .get(index as usize)
+                    .ok_or(format!("Invalid variant index {index}"))?;
+
+                match variant.type_decl() {
+                    None => Ok(Value::String(variant.name().to_string())),
+                    Some(data_type) => {
+                        let data = self.decode_value(data_type, input)?;
+                        Ok(Value::Object(Map::from_iter([(variant.name().to_string(), data)])))
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Converts an argument written as text to a value, the text can be JSON or
+// a plain text (like "0x..." or the name of a variant). Integers are kept as
+// strings so the big numbers (u128) are not rounded.
+pub fn text_arg(text: &str) -> Value {
+    let is_integer = text
+        .strip_prefix('-')
+        .unwrap_or(text)
+        .chars()
+        .all(|c| c.is_ascii_digit());
+
+    if is_integer && !text.is_empty() {
+        return Value::String(text.to_string());
+    }
+
+    serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()))
+}
+
+// Returns the bytes as a "0x..." hex string
+pub fn hex_encode(bytes: &[u8]) -> String {
+    let hex: String = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    format!("0x{hex}")
+}
+
+// Reads a hex string, with or without the "0x" prefix
+pub fn hex_decode(text: &str) -> Result<Vec<u8>, String> {
+    let hex = text.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+
+    if hex.len() % 2 == 1 {
+        return Err(format!("Odd number of hex digits: {text}"));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| {
+            hex.get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or(format!("Invalid hex: {text}"))
+        })
+        .collect()
+}
+
+fn parse_idl(idl: &str) -> Result<Program, String> {
+    ast::parse_idl(idl).map_err(|error| error.to_string())
+}
+
+fn is_u8(type_decl: &TypeDecl) -> bool {
+    matches!(type_decl, TypeDecl::Id(TypeId::Primitive(PrimitiveType::U8)))
+}
+
+// Returns the number of bytes of the ids and hashes
+fn hash_len(primitive: &PrimitiveType) -> Option<usize> {
+    match primitive {
+        PrimitiveType::ActorId | PrimitiveType::CodeId | PrimitiveType::MessageId | PrimitiveType::H256 => Some(32),
+        PrimitiveType::H160 => Some(20),
+        _ => None
+    }
+}
+
+// Returns the bytes of a hex string, if the items are bytes and the value is
+// a string (the bytes can also be written as an array of numbers)
+fn hex_bytes(item: &TypeDecl, value: &Value) -> Result<Option<Vec<u8>>, String> {
+    match value {
+        Value::String(hex) if is_u8(item) => hex_decode(hex).map(Some),
+        _ => Ok(None)
+    }
+}
+
+// Returns the only entry of an object, used for enums and results
+fn single_entry(value: &Value) -> Option<(&str, &Value)> {
+    let object = value.as_object().filter(|object| object.len() == 1)?;
+
+    object
+        .iter()
+        .next()
+        .map(|(name, data)| (name.as_str(), data))
+}
+
+fn encode_primitive(primitive: &PrimitiveType, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    if let Some(len) = hash_len(primitive) {
+        let bytes = value
+            .as_str()
+            .map(hex_decode)
+            .transpose()?
+            .filter(|bytes| bytes.len() == len)
+            .ok_or(format!("Expected a hex string of {len} bytes for {primitive:?}: {value}"))?;
+
+        out.extend(bytes);
+        return Ok(());
+    }
+
+    match primitive {
+        PrimitiveType::Null => {},
+        PrimitiveType::Bool => value
+            .as_bool()
+            .ok_or(format!("Expected a bool: {value}"))?
+            .encode_to(out),
+        // The chars are encoded as their code (u32)
+        PrimitiveType::Char => {
+            let mut chars = value.as_str().unwrap_or_default().chars();
+            let c = chars
+                .next()
+                .filter(|_| chars.next().is_none())
+                .ok_or(format!("Expected a char: {value}"))?;
+            (c as u32).encode_to(out);
+        },
+        PrimitiveType::Str => value
+            .as_str()
+            .ok_or(format!("Expected a string: {value}"))?
+            .encode_to(out),
+        PrimitiveType::U8 => unsigned::<u8>(value)?.encode_to(out),
+        PrimitiveType::U16 => unsigned::<u16>(value)?.encode_to(out),
+        PrimitiveType::U32 => unsigned::<u32>(value)?.encode_to(out),
+        PrimitiveType::U64 => unsigned::<u64>(value)?.encode_to(out),
+        PrimitiveType::U128 => unsigned::<u128>(value)?.encode_to(out),
+        PrimitiveType::I8 => signed::<i8>(value)?.encode_to(out),
+        PrimitiveType::I16 => signed::<i16>(value)?.encode_to(out),
+        PrimitiveType::I32 => signed::<i32>(value)?.encode_to(out),
+        PrimitiveType::I64 => signed::<i64>(value)?.encode_to(out),
+        PrimitiveType::I128 => signed::<i128>(value)?.encode_to(out),
+        _ => return Err(format!("Unsupported type: {primitive:?}"))
+    }
+
+    Ok(())
+}
+
+fn decode_primitive(primitive: &PrimitiveType, input: &mut &[u8]) -> Result<Value, String> {
+    if let Some(len) = hash_len(primitive) {
+        return Ok(Value::String(hex_encode(&take(input, len)?)));
+    }
+
+    let value = match primitive {
+        PrimitiveType::Null => Value::Null,
+        PrimitiveType::Bool => Value::Bool(decode(input)?),
+        PrimitiveType::Char => {
+            let code: u32 = decode(input)?;
+            let c = char::from_u32(code).ok_or(format!("Invalid char: {code}"))?;
+            Value::String(c.to_string())
+        },
+        PrimitiveType::Str => Value::String(decode(input)?),
+        PrimitiveType::U8 => Value::from(decode::<u8>(input)?),
+        PrimitiveType::U16 => Value::from(decode::<u16>(input)?),
+        PrimitiveType::U32 => Value::from(decode::<u32>(input)?),
+        PrimitiveType::U64 => Value::from(decode::<u64>(input)?),
+        PrimitiveType::U128 => {
+            let number: u128 = decode(input)?;
+            u64::try_from(number)
+                .map(Value::from)
+                .unwrap_or_else(|_| Value::String(number.to_string()))
+        },
+        PrimitiveType::I8 => Value::from(decode::<i8>(input)?),
+        PrimitiveType::I16 => Value::from(decode::<i16>(input)?),
+        PrimitiveType::I32 => Value::from(decode::<i32>(input)?),
+        PrimitiveType::I64 => Value::from(decode::<i64>(input)?),
+        PrimitiveType::I128 => {
+            let number: i128 = decode(input)?;
+            i64::try_from(number)
+                .map(Value::from)
+                .unwrap_or_else(|_| Value::String(number.to_string()))
+        },
+        _ => return Err(format!("Unsupported type: {primitive:?}"))
+    };
+
+    Ok(value)
+}
+
+// Reads an unsigned integer from a number or a string (for big numbers)
+fn unsigned<T: TryFrom<u128>>(value: &Value) -> Result<T, String> {
+    let number = match value {
+        Value::Number(number) => number.as_u64().map(u128::from),
+        Value::String(text) => text.parse().ok(),
+        _ => None
+    };
+
+    number
+        .and_then(|number| T::try_from(number).ok())
+        .ok_or(format!("Invalid unsigned integer for the type: {value}"))
+}
+
+// Reads a signed integer from a number or a string (for big numbers)
+fn signed<T: TryFrom<i128>>(value: &Value) -> Result<T, String> {
+    let number = match value {
+        Value::Number(number) => number.as_i64().map(i128::from),
+        Value::String(text) => text.parse().ok(),
+        _ => None
+    };
+
+    number
+        .and_then(|number| T::try_from(number).ok())
+        .ok_or(format!("Invalid integer for the type: {value}"))
+}
+
+// Decodes the next value of the input
+fn decode<T: Decode>(input: &mut &[u8]) -> Result<T, String> {
+    T::decode(input).map_err(|error| format!("Invalid payload: {error}"))
+}
+
+// Reads the next bytes of the input
+fn take(input: &mut &[u8], len: usize) -> Result<Vec<u8>, String> {
+    if input.len() < len {
+        return Err(format!("Unexpected end of the payload, {len} bytes expected"));
+    }
+
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+
+    Ok(bytes.to_vec())
+}
+
+fn check_end(input: &[u8]) -> Result<(), String> {
+    if !input.is_empty() {
+        return Err(format!("{} bytes were not decoded: {}", input.len(), hex_encode(input)));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Command line tool to encode and decode the payloads of a contract offline,
+// with the IDL of the contract (the IDL of the proxy by default):
+//
+//     payload-cli [--idl <app.idl>] encode <Service/Method | Constructor> [args...]
+//     payload-cli [--idl <app.idl>] decode-call <hex>
+//     payload-cli [--idl <app.idl>] decode-reply <hex>
+//     payload-cli [--idl <app.idl>] decode <Type> <hex>
+//
+// The arguments are written as text ("0x...", "PingContract", "10") or as JSON
+// ('{"Some": ...}', '["0x...", 2]'), the decoded values are printed as JSON.
+use payload_cli::{
+    hex_decode,
+    hex_encode,
+    text_arg,
+    PayloadCodec
+};
+use serde_json::{
+    json,
+    Value
+};
+use std::{
+    env,
+    path::PathBuf,
+    process::ExitCode
+};
+
+const USAGE: &str = "Usage:
+    payload-cli [--idl <app.idl>] encode <Service/Method | Constructor> [args...]
+    payload-cli [--idl <app.idl>] decode-call <hex>
+    payload-cli [--idl <app.idl>] decode-reply <hex>
+    payload-cli [--idl <app.idl>] decode <Type> <hex>";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let idl_path = match args.first().map(String::as_str) {
+        Some("--idl") if args.len() > 1 => {
+            let path = PathBuf::from(&args[1]);
+            args.drain(..2);
+            path
+        },
+        _ => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../proxy_contract/wasm/app.idl")
+    };
+
+    let codec = match PayloadCodec::load(&idl_path) {
+        Ok(codec) => codec,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["encode", route, call_args @ ..] => {
+            let call_args: Vec<Value> = call_args
+                .iter()
+                .map(|arg| text_arg(arg))
+                .collect();
+
+            codec
+                .encode_call(route, &call_args)
+                .map(|payload| hex_encode(&payload))
+        },
+        ["decode-call", hex] => hex_decode(hex)
+            .and_then(|payload| codec.decode_call(&payload))
+            .map(|(route, args)| pretty(json!({ "route": route, "args": args }))),
+        ["decode-reply", hex] => hex_decode(hex)
+            .and_then(|payload| codec.decode_reply(&payload))
+            .map(|(route, output)| pretty(json!({ "route": route, "reply": output }))),
+        ["decode", type_name, hex] => hex_decode(hex)
+            .and_then(|bytes| codec.decode_type(type_name, &bytes))
+            .map(pretty),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn pretty(value: Value) -> String {
+    serde_json::to_string_pretty(&value).expect("The values are always serializable")
+}
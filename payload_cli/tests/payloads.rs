@@ -0,0 +1,177 @@
+// Tests of the payload encoder, the payloads are compared with the ones of the
+// generated clients (app_client.rs) of the proxy, so the encoder keeps the same
+// encoding as sails
+use payload_cli::{
+    hex_decode,
+    hex_encode,
+    text_arg,
+    PayloadCodec
+};
+use sails_rs::{
+    calls::ActionIo,
+    prelude::*
+};
+use serde_json::{
+    json,
+    Value
+};
+use std::path::Path;
+
+// The variants of ProxyRoute are the names of the routes ("Call...")
+#[allow(dead_code, clippy::enum_variant_names)]
+mod proxy_client {
+    include!("../../proxy_contract/wasm/app_client.rs");
+}
+
+use proxy_client::{
+    app_factory,
+    ping_caller,
+    proxy,
+    CallsPage,
+    PingEnum,
+    ProxyEvent,
+    ProxyPingCallerEvent,
+    ProxyRoute,
+    ProxyTarget
+};
+
+const ADMIN: [u8; 32] = [7; 32];
+
+fn codec() -> PayloadCodec {
+    PayloadCodec::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../proxy_contract/wasm/app.idl"))
+        .unwrap()
+}
+
+// Encodes the call with the codec, from the arguments written as text
+fn encode(route: &str, args: &[&str]) -> Vec<u8> {
+    let args: Vec<Value> = args
+        .iter()
+        .map(|arg| text_arg(arg))
+        .collect();
+
+    codec().encode_call(route, &args).unwrap()
+}
+
+// Returns the payload of the reply of a method, as it is sent by the program
+fn reply<A: ActionIo>(output: impl Encode) -> Vec<u8> {
+    [A::ROUTE, output.encode().as_slice()].concat()
+}
+
+#[test]
+fn calls_are_encoded_as_in_the_clients() {
+    let admin = hex_encode(&ADMIN);
+
+    assert_eq!(
+        encode("Proxy/AddAdmin", &[&admin]),
+        proxy::io::AddAdmin::encode_call(ADMIN.into())
+    );
+    assert_eq!(
+        encode("Proxy/SetRateLimit", &["CallPing", "5", "100"]),
+        proxy::io::SetRateLimit::encode_call(ProxyRoute::CallPing, 5, 100)
+    );
+    assert_eq!(
+        encode("Proxy/ChangeContractId", &["TrafficLightContract", &admin]),
+        proxy::io::ChangeContractId::encode_call(ProxyTarget::TrafficLightContract, ADMIN.into())
+    );
+    assert_eq!(
+        encode("PingCaller/CallCallsByActor", &[&admin, "18446744073709551615", "1"]),
+        ping_caller::io::CallCallsByActor::encode_call(ADMIN.into(), u64::MAX, 1)
+    );
+    assert_eq!(
+        encode("PingCaller/CallPingIdempotent", &[&admin]),
+        ping_caller::io::CallPingIdempotent::encode_call(H256::from(ADMIN))
+    );
+    assert_eq!(
+        encode("NewWithContractsId", &[&admin, &admin]),
+        app_factory::io::NewWithContractsId::encode_call(ADMIN.into(), ADMIN.into())
+    );
+}
+
+#[test]
+fn calls_of_the_clients_are_decoded() {
+    let payload = proxy::io::SetRateLimit::encode_call(ProxyRoute::CallGreen, 3, 20);
+
+    let (route, args) = codec().decode_call(&payload).unwrap();
+    assert_eq!(route, "Proxy/SetRateLimit");
+    assert_eq!(args, json!({ "route": "CallGreen", "max_calls": 3, "window_blocks": 20 }));
+
+    // The decoded arguments are encoded to the same payload
+    let args: Vec<Value> = ["route", "max_calls", "window_blocks"]
+        .iter()
+        .map(|name| args[*name].clone())
+        .collect();
+    assert_eq!(codec().encode_call(&route, &args).unwrap(), payload);
+}
+
+#[test]
+fn replies_of_the_program_are_decoded() {
+    let payload = reply::<ping_caller::io::CallPing>(ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+    assert_eq!(
+        codec().decode_reply(&payload).unwrap(),
+        ("PingCaller/CallPing".to_string(), json!({ "PingContractResponse": "Pong" }))
+    );
+
+    let page = CallsPage {
+        calls: vec![(ADMIN.into(), PingEnum::Ping)],
+        next_offset: Some(7)
+    };
+    let payload = reply::<ping_caller::io::CallCallsPage>(ProxyPingCallerEvent::PingContractStateCallsPage(page));
+    assert_eq!(
+        codec().decode_reply(&payload).unwrap().1,
+        json!({ "PingContractStateCallsPage": { "calls": [[hex_encode(&ADMIN), "Ping"]], "next_offset": 7 } })
+    );
+}
+
+#[test]
+fn types_of_the_idl_are_decoded() {
+    let event = ProxyEvent::ContractIds(vec![(ProxyTarget::PingContract, ADMIN.into())]);
+    assert_eq!(
+        codec().decode_type("ProxyEvent", &event.encode()).unwrap(),
+        json!({ "ContractIds": [["PingContract", hex_encode(&ADMIN)]] })
+    );
+
+    let big_value = u128::MAX;
+    assert_eq!(
+        codec().decode_type("opt u128", &Some(big_value).encode()).unwrap(),
+        json!(big_value.to_string())
+    );
+
+    let bytes = vec![1u8, 2, 3];
+    assert_eq!(codec().decode_type("vec u8", &bytes.encode()).unwrap(), json!("0x010203"));
+}
+
+#[test]
+fn invalid_calls_are_rejected() {
+    let codec = codec();
+
+    assert!(codec.encode_call("Proxy/AddAdmin", &[]).is_err());
+    assert!(codec.encode_call("Proxy/Unknown", &[]).is_err());
+    assert!(codec.encode_call("Proxy/SetRateLimit", &[json!("CallUnknown"), json!(5), json!(100)]).is_err());
+    assert!(codec.encode_call("Proxy/AddAdmin", &[json!("0x0102")]).is_err());
+}
+
+#[test]
+fn payloads_with_trailing_bytes_are_rejected() {
+    let mut payload = proxy::io::AddAdmin::encode_call(ADMIN.into());
+    payload.push(0);
+
+    assert!(codec().decode_call(&payload).is_err());
+    assert!(codec().decode_type("PingEnum", &[0, 0]).is_err());
+}
+
+#[test]
+fn text_args_are_read_as_json_or_text() {
+    assert_eq!(text_arg("340282366920938463463374607431768211455"), json!("340282366920938463463374607431768211455"));
+    assert_eq!(text_arg("CallPing"), json!("CallPing"));
+    assert_eq!(text_arg("null"), Value::Null);
+    assert_eq!(text_arg(r#"{"Delay": 3}"#), json!({ "Delay": 3 }));
+}
+
+#[test]
+fn hex_is_encoded_and_decoded() {
+    assert_eq!(hex_encode(&[0, 255, 16]), "0x00ff10");
+    assert_eq!(hex_decode("0x00ff10").unwrap(), vec![0, 255, 16]);
+    assert_eq!(hex_decode("00FF10").unwrap(), vec![0, 255, 16]);
+    assert!(hex_decode("0x0").is_err());
+    assert!(hex_decode("0xzz").is_err());
+}
@@ -1,30 +1,43 @@
 #![no_std]
 use sails_rs::{
     prelude::*,
+    cell::RefCell,
     gstd::msg
 };
 
 pub mod service;
 pub mod states;
+pub mod program_info;
 
 use service::ping_pong_service::PingService;
+use states::ping_pong_state::PingState;
 
-// Ping program struct
-#[derive(Default)]
-pub struct PingProgram;
+// Ping program struct, it owns the state of the contract. The state is created by
+// the constructor, so the services can't use it before the program is initialized
+// (the messages sent before the constructor fail instead)
+pub struct PingProgram {
+    ping_state: RefCell<PingState>
+}
 
+// The default program is the one created by the constructor (the caller is the
+// owner of the contract)
+impl Default for PingProgram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 // Program of the contract
 #[program]  
 impl PingProgram {
     pub fn new() -> Self {
-        PingService::seed(msg::source());
-
-        Self
+        Self {
+            ping_state: RefCell::new(PingState::new(msg::source()))
+        }
     }
 
     #[route("Ping")]
-    pub fn ping_svc(&self) -> PingService {
-        PingService::new()
+    pub fn ping_svc(&self) -> PingService<'_> {
+        PingService::new(self.ping_state.borrow_mut())
     }
 }
\ No newline at end of file
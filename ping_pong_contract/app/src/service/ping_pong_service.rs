@@ -1,28 +1,61 @@
 use sails_rs::{
     prelude::*,
+    cell::RefMut,
     gstd::msg
 };
+use core::num::NonZeroU32;
 
-use crate::states::ping_pong_state::*;
+use crate::states::{
+    ping_pong_state::*,
+    call_history_state::{
+        CallsPage,
+        HistoryInfo
+    },
+    call_counters_state::{
+        ActorCalls,
+        CallTotals
+    }
+};
+use crate::program_info::ProgramInfo;
+
+// Struct that will be used for the Ping service, it contains a RefMut to the
+// state that is owned by the program (PingProgram)
+pub struct PingService<'a> {
+    ping_state: RefMut<'a, PingState>
+}
 
-// Struct that will be used for the Ping service
-#[derive(Default)]
-pub struct PingService;
+impl PingService<'_> {
+    // Method to store a call made by a user
+    fn save_call(&mut self, caller: ActorId, action: PingEnum) {
+        self.ping_state
+            .last_who_call = (caller, action.clone());
+
+        self.ping_state
+            .call_counters
+            .record(caller, &action);
+
+        self.ping_state
+            .all_calls
+            .push((caller, action));
+    }
 
-impl PingService {
-    // This related functions initiate the state of the service
-    // this functions can be called only once
-    pub fn seed(caller: ActorId) {
-        PingState::init_state(caller);
+    // Method to check that the caller is a trusted proxy, only trusted
+    // proxies can send messages on behalf of the users
+    fn only_trusted_proxy(&self) {
+        if !self.ping_state.is_trusted_proxy(msg::source()) {
+            panic!("Only trusted proxies can call on behalf of users");
+        }
     }
 }
 
 // Contract's Ping Service
 #[service]
-impl PingService {
+impl<'a> PingService<'a> {
     // Service's contructor
-    pub fn new() -> Self {
-        Self
+    pub fn new(ping_state: RefMut<'a, PingState>) -> Self {
+        Self {
+            ping_state
+        }
     }
 
     // Method of the service, will return a PingEnum variant
@@ -30,12 +63,7 @@ impl PingService {
     pub fn ping(&mut self) -> PingEnum {
         let caller: ActorId = msg::source();
 
-        PingState::state_mut()
-            .last_who_call = (caller.clone(), PingEnum::Ping);
-
-        PingState::state_mut()
-            .all_calls
-            .push((caller, PingEnum::Ping));
+        self.save_call(caller, PingEnum::Ping);
 
         PingEnum::Pong
     }
@@ -45,29 +73,203 @@ impl PingService {
     pub fn pong(&mut self) -> PingEnum {
         let caller: ActorId = msg::source();
 
-        PingState::state_mut()
-            .last_who_call = (caller.clone(), PingEnum::Pong);
+        self.save_call(caller, PingEnum::Pong);
 
-        PingState::state_mut()
-            .all_calls
-            .push((caller, PingEnum::Pong));
+        PingEnum::Ping
+    }
+
+    // Method of the service, same as "ping", but the call is stored for the
+    // given user. Only trusted proxies can call it (trusted forwarder)
+    pub fn ping_on_behalf_of(&mut self, user: ActorId) -> PingEnum {
+        self.only_trusted_proxy();
+
+        self.save_call(user, PingEnum::Ping);
+
+        PingEnum::Pong
+    }
+
+    // Method of the service, same as "pong", but the call is stored for the
+    // given user. Only trusted proxies can call it (trusted forwarder)
+    pub fn pong_on_behalf_of(&mut self, user: ActorId) -> PingEnum {
+        self.only_trusted_proxy();
+
+        self.save_call(user, PingEnum::Pong);
 
         PingEnum::Ping
     }
 
+    // Method to add a proxy that can send messages on behalf of the users
+    // Only admins can call it
+    pub fn add_trusted_proxy(&mut self, proxy: ActorId) -> PingAdminEvent {
+        if !self.ping_state.is_admin(msg::source()) {
+            return PingAdminEvent::Error(
+                PingAdminError::OnlyAdminsCanChangeTrustedProxies
+            );
+        }
+
+        if self.ping_state.is_trusted_proxy(proxy) {
+            return PingAdminEvent::Error(
+                PingAdminError::TrustedProxyExists(proxy)
+            );
+        }
+
+        self.ping_state
+            .trusted_proxies
+            .push(proxy);
+
+        PingAdminEvent::TrustedProxyAdded(proxy)
+    }
+
+    // Method to remove a trusted proxy, only admins can call it
+    pub fn remove_trusted_proxy(&mut self, proxy: ActorId) -> PingAdminEvent {
+        if !self.ping_state.is_admin(msg::source()) {
+            return PingAdminEvent::Error(
+                PingAdminError::OnlyAdminsCanChangeTrustedProxies
+            );
+        }
+
+        if !self.ping_state.is_trusted_proxy(proxy) {
+            return PingAdminEvent::Error(
+                PingAdminError::TrustedProxyNotFound(proxy)
+            );
+        }
+
+        self.ping_state
+            .trusted_proxies
+            .retain(|trusted_proxy| *trusted_proxy != proxy);
+
+        PingAdminEvent::TrustedProxyRemoved(proxy)
+    }
+
     // Method that will return data from the last caller
     // Its a query because it only reads the state (it not change the state)
     pub fn last_who_call(&self) -> UserData {
-        let (last_caller, action) = &PingState::state_ref().last_who_call;
+        let (last_caller, action) = &self.ping_state.last_who_call;
 
         (*last_caller, action.clone())
     }
 
-    // Method that will return all the calls of the contract
+    // Method to change the number of calls that are stored in the history, if
+    // there are more calls than the new capacity, the oldest ones are evicted.
+    // Only admins can call it
+    pub fn set_history_capacity(&mut self, capacity: u32) -> PingAdminEvent {
+        if !self.ping_state.is_admin(msg::source()) {
+            return PingAdminEvent::Error(
+                PingAdminError::OnlyAdminsCanChangeHistoryCapacity
+            );
+        }
+
+        let Some(capacity) = NonZeroU32::new(capacity) else {
+            return PingAdminEvent::Error(
+                PingAdminError::InvalidHistoryCapacity
+            );
+        };
+
+        self.ping_state
+            .all_calls
+            .set_capacity(capacity);
+
+        PingAdminEvent::HistoryCapacitySet(capacity.get())
+    }
+
+    // Method that will return the last calls of the contract (the calls in the
+    // history), from the oldest to the newest
     // Its a query because it only reads the state (it not change the state)
     pub fn all_calls(&self) -> Vec<UserData> {
-        PingState::state_ref()
+        self.ping_state
+            .all_calls
+            .calls()
+    }
+
+    // Method that will return a page of the history, from the oldest to the newest
+    // call, with the offset of the next page (up to 50 calls in each page), the
+    // offsets are the sequence numbers of the calls (0 is the first call)
+    pub fn calls_page(&self, offset: u64, limit: u32) -> CallsPage {
+        self.ping_state
             .all_calls
+            .page(offset, limit, None)
+    }
+
+    // Method that will return a page of the calls of an actor in the history, the
+    // offset of the next page is the sequence number of its next call
+    pub fn calls_by_actor(&self, actor: ActorId, offset: u64, limit: u32) -> CallsPage {
+        self.ping_state
+            .all_calls
+            .page(offset, limit, Some(actor))
+    }
+
+    // Method that will return the number of calls in the history
+    pub fn calls_count(&self) -> u32 {
+        self.ping_state
+            .all_calls
+            .len()
+    }
+
+    // Method that will return the capacity of the history, the number of calls
+    // stored and the number of calls that were evicted
+    pub fn history_info(&self) -> HistoryInfo {
+        self.ping_state
+            .all_calls
+            .info()
+    }
+
+    // Method that will return the number of pings and pongs of an actor (None if
+    // the actor never called the contract or its counters were evicted)
+    pub fn actor_calls(&self, actor: ActorId) -> Option<ActorCalls> {
+        self.ping_state
+            .call_counters
+            .actor(actor)
+    }
+
+    // Method that will return the number of pings and pongs of all the actors, the
+    // number of actors that called the contract and the actors that were evicted
+    pub fn call_totals(&self) -> CallTotals {
+        self.ping_state
+            .call_counters
+            .totals()
+    }
+
+    // Method that will return the actors with more calls, sorted by their number of
+    // calls (up to 50 actors)
+    pub fn leaderboard(&self, limit: u32) -> Vec<(ActorId, ActorCalls)> {
+        self.ping_state
+            .call_counters
+            .leaderboard(limit)
+    }
+
+    // Method that will return the proxies that can call on behalf of the users
+    pub fn trusted_proxies(&self) -> Vec<ActorId> {
+        self.ping_state
+            .trusted_proxies
             .to_owned()
     }
+
+    // Method that will return the version, git revision, IDL hash and services
+    // of the deployed build, to detect which build is deployed
+    pub fn info(&self) -> ProgramInfo {
+        ProgramInfo::current()
+    }
+}
+
+// Enum to set the responses of the admin methods
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PingAdminEvent {
+    TrustedProxyAdded(ActorId),
+    TrustedProxyRemoved(ActorId),
+    Error(PingAdminError),
+    HistoryCapacitySet(u32)
+}
+
+// Enum to set the errors of the admin methods
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PingAdminError {
+    OnlyAdminsCanChangeTrustedProxies,
+    TrustedProxyExists(ActorId),
+    TrustedProxyNotFound(ActorId),
+    OnlyAdminsCanChangeHistoryCapacity,
+    InvalidHistoryCapacity
 }
@@ -0,0 +1,158 @@
+use sails_rs::{
+    prelude::*,
+    collections::{
+        BTreeMap,
+        BTreeSet
+    }
+};
+
+use super::ping_pong_state::PingEnum;
+
+// Max number of actors that are returned in the leaderboard
+pub const MAX_LEADERBOARD_SIZE: u32 = 50;
+// Max number of actors with their own counters, when a new actor calls the
+// contract and there are more, the counters of the actor with less calls are removed
+pub const MAX_TRACKED_ACTORS: u32 = 1_000;
+
+// Key of an actor in the ranking: its total calls, a tie breaker and the actor. The
+// actors that reach a total first have a greater tie breaker (u64::MAX minus the
+// number of calls that were counted before)
+type RankKey = (u64, u64, ActorId);
+
+// Counters of the calls of each actor and of all the actors, they are not
+// affected by the capacity of the history (the calls are never evicted)
+#[derive(Encode, Decode, TypeInfo, Default)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallCounters {
+    actors: BTreeMap<ActorId, TrackedActor>,
+    // Actors sorted by their total calls, the leaderboard is read from the end and
+    // the actor with less calls is the first one, so no query reads all the actors
+    ranking: BTreeSet<RankKey>,
+    totals: ActorCalls,
+    unique_callers: u64,
+    evicted_callers: u64
+}
+
+// Counters of an actor and the tie breaker of its key in the ranking
+#[derive(Encode, Decode, TypeInfo, Default, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+struct TrackedActor {
+    calls: ActorCalls,
+    tie_breaker: u64
+}
+
+// Number of pings and pongs of an actor
+#[derive(Encode, Decode, TypeInfo, Default, Clone, Copy, PartialEq, Debug)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ActorCalls {
+    pub pings: u64,
+    pub pongs: u64
+}
+
+// Struct to send the global counters to the user, "unique_callers" is the number
+// of actors that got counters (an actor whose counters were evicted is counted
+// again if it calls again) and "evicted_callers" the number of actors whose
+// counters were removed
+#[derive(Encode, Decode, TypeInfo, PartialEq, Debug)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallTotals {
+    pub pings: u64,
+    pub pongs: u64,
+    pub unique_callers: u64,
+    pub evicted_callers: u64
+}
+
+impl ActorCalls {
+    // Returns the number of pings and pongs
+    pub fn total(&self) -> u64 {
+        self.pings.saturating_add(self.pongs)
+    }
+
+    fn record(&mut self, action: &PingEnum) {
+        match action {
+            PingEnum::Ping => self.pings = self.pings.saturating_add(1),
+            PingEnum::Pong => self.pongs = self.pongs.saturating_add(1)
+        }
+    }
+}
+
+impl TrackedActor {
+    fn rank_key(&self, actor: ActorId) -> RankKey {
+        (self.calls.total(), self.tie_breaker, actor)
+    }
+}
+
+impl CallCounters {
+    // Method to count a call of the actor, if the actor is new and there are
+    // MAX_TRACKED_ACTORS, the counters of the actor with less calls are removed
+    pub fn record(&mut self, actor: ActorId, action: &PingEnum) {
+        let tie_breaker = u64::MAX - self.totals.total();
+        self.totals.record(action);
+
+        let mut tracked = match self.actors.get(&actor) {
+            Some(tracked) => {
+                self.ranking.remove(&tracked.rank_key(actor));
+                *tracked
+            },
+            None => {
+                if self.actors.len() >= MAX_TRACKED_ACTORS as usize {
+                    self.remove_least_active();
+                }
+
+                self.unique_callers = self.unique_callers.saturating_add(1);
+                TrackedActor::default()
+            }
+        };
+
+        tracked.calls.record(action);
+        tracked.tie_breaker = tie_breaker;
+
+        self.ranking.insert(tracked.rank_key(actor));
+        self.actors.insert(actor, tracked);
+    }
+
+    // Returns the counters of the actor, None if the actor has no counters (it
+    // never called the contract or its counters were evicted)
+    pub fn actor(&self, actor: ActorId) -> Option<ActorCalls> {
+        self.actors
+            .get(&actor)
+            .map(|tracked| tracked.calls)
+    }
+
+    // Returns the counters of all the actors, the number of actors that called
+    // the contract and the number of actors whose counters were evicted
+    pub fn totals(&self) -> CallTotals {
+        CallTotals {
+            pings: self.totals.pings,
+            pongs: self.totals.pongs,
+            unique_callers: self.unique_callers,
+            evicted_callers: self.evicted_callers
+        }
+    }
+
+    // Returns the actors with more calls (up to MAX_LEADERBOARD_SIZE), sorted by
+    // their total calls, the actors with the same total are sorted by the order in
+    // which they reached it
+    pub fn leaderboard(&self, limit: u32) -> Vec<(ActorId, ActorCalls)> {
+        self.ranking
+            .iter()
+            .rev()
+            .take(limit.min(MAX_LEADERBOARD_SIZE) as usize)
+            .map(|(_, _, actor)| (*actor, self.actors[actor].calls))
+            .collect()
+    }
+
+    // Removes the counters of the actor with less calls (the last one to reach its
+    // total on ties), to make room for a new actor. There are more tracked actors
+    // than actors in the leaderboard, so the leaderboard is never changed
+    fn remove_least_active(&mut self) {
+        if let Some((_, _, actor)) = self.ranking.pop_first() {
+            self.actors.remove(&actor);
+            self.evicted_callers = self.evicted_callers.saturating_add(1);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use sails_rs::prelude::*;
+use core::num::NonZeroU32;
+
+use super::ping_pong_state::UserData;
+
+// Default number of calls that are stored in the history
+pub const DEFAULT_HISTORY_CAPACITY: NonZeroU32 = match NonZeroU32::new(100) {
+    Some(capacity) => capacity,
+    None => panic!("The capacity of the history has to be greater than zero")
+};
+// Max number of calls that are returned in a page
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+// Ring buffer with the last calls of the contract, when it is full the new calls
+// replace the oldest ones and the evicted counter is increased
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallHistory {
+    entries: Vec<UserData>,
+    // Position of the oldest call once the buffer is full (where the next
+    // call is written)
+    next: u32,
+    capacity: NonZeroU32,
+    evicted: u64
+}
+
+// Struct to send the capacity and the usage of the history to the user
+#[derive(Encode, Decode, TypeInfo, PartialEq, Debug)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct HistoryInfo {
+    pub capacity: u32,
+    pub len: u32,
+    pub evicted: u64
+}
+
+// Struct to send a page of the history to the user, "next_offset" is the offset
+// of the next page (None if it is the last page)
+#[derive(Encode, Decode, TypeInfo, PartialEq, Debug)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallsPage {
+    pub calls: Vec<UserData>,
+    pub next_offset: Option<u64>
+}
+
+impl Default for CallHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_CAPACITY)
+    }
+}
+
+impl CallHistory {
+    // Related function to create an empty history
+    pub fn new(capacity: NonZeroU32) -> Self {
+        Self {
+            entries: Vec::new(),
+            next: 0,
+            capacity,
+            evicted: 0
+        }
+    }
+
+    // Method to store a call, the oldest call is evicted if the history is full
+    pub fn push(&mut self, call: UserData) {
+        if self.entries.len() < self.capacity.get() as usize {
+            self.entries.push(call);
+            return;
+        }
+
+        self.entries[self.next as usize] = call;
+        self.next = (self.next + 1) % self.capacity;
+        self.evicted += 1;
+    }
+
+    // Returns the calls from the oldest to the newest
+    pub fn calls(&self) -> Vec<UserData> {
+        self.iter()
+            .cloned()
+            .collect()
+    }
+
+    // Returns the number of calls in the history
+    pub fn len(&self) -> u32 {
+        self.entries.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Returns a page of the history (from the oldest to the newest call), the limit
+    // is the max number of calls (up to MAX_PAGE_SIZE). The offset is the sequence
+    // number of the call where the page starts (the number of calls stored before
+    // it), so the offsets don't move when the oldest calls are evicted, if the call
+    // was evicted the page starts with the oldest call. If an actor is given, only
+    // its calls are returned, and the offset of the next page skips the calls of others
+    pub fn page(&self, offset: u64, limit: u32, actor: Option<ActorId>) -> CallsPage {
+        let limit = limit.min(MAX_PAGE_SIZE) as usize;
+        let start = offset.saturating_sub(self.evicted);
+
+        let mut matching = self.iter()
+            .enumerate()
+            .skip(usize::try_from(start).unwrap_or(usize::MAX))
+            .filter(|(_, (caller, _))| match actor {
+                Some(actor) => *caller == actor,
+                None => true
+            });
+
+        let calls = matching
+            .by_ref()
+            .take(limit)
+            .map(|(_, call)| call.clone())
+            .collect();
+
+        CallsPage {
+            calls,
+            next_offset: matching
+                .next()
+                .map(|(position, _)| self.evicted + position as u64)
+        }
+    }
+
+    // Method to change the capacity, if the history has more calls than the new
+    // capacity, the oldest calls are evicted
+    pub fn set_capacity(&mut self, capacity: NonZeroU32) {
+        let mut calls = self.calls();
+        let excess = calls.len().saturating_sub(capacity.get() as usize);
+
+        calls.drain(..excess);
+
+        self.entries = calls;
+        self.next = 0;
+        self.capacity = capacity;
+        self.evicted += excess as u64;
+    }
+
+    // Returns the capacity, the number of stored calls and the evicted calls
+    pub fn info(&self) -> HistoryInfo {
+        HistoryInfo {
+            capacity: self.capacity.get(),
+            len: self.len(),
+            evicted: self.evicted
+        }
+    }
+
+    // Iterator over the calls from the oldest to the newest
+    fn iter(&self) -> impl Iterator<Item = &UserData> {
+        let (newest, oldest) = self.entries.split_at(self.next as usize);
+
+        oldest
+            .iter()
+            .chain(newest)
+    }
+}
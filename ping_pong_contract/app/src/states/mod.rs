@@ -1 +1,3 @@
-pub mod ping_pong_state;
\ No newline at end of file
+pub mod ping_pong_state;
+pub mod call_history_state;
+pub mod call_counters_state;
\ No newline at end of file
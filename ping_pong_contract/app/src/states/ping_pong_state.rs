@@ -1,49 +1,51 @@
 use sails_rs::prelude::*;
 
+use super::{
+    call_history_state::CallHistory,
+    call_counters_state::CallCounters
+};
+
 // Create the type aliase for the state.
 pub type UserData = (ActorId, PingEnum);
 
-// Static mut variable that will store the state of the contract
-static mut PING_STATE:Option<PingState> = None;
-
 // Struct for the state of the contract
 #[derive(Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct PingState {
     pub last_who_call: UserData,
-    pub all_calls: Vec<UserData>
+    // Last calls of the contract, the oldest ones are evicted when it is full
+    pub all_calls: CallHistory,
+    // Number of pings and pongs of each actor
+    pub call_counters: CallCounters,
+    pub admins: Vec<ActorId>,
+    pub trusted_proxies: Vec<ActorId>
 }
 
 // Related functions for PingState struct
 impl PingState {
-    // Init the state of the contract.
-    // This related function need to be called only once
-    pub fn init_state(caller: ActorId) {
-        unsafe {
-            PING_STATE = Some(PingState {
-                last_who_call: (caller, PingEnum::Ping),
-                all_calls: Vec::new()
-            })
+    // Related function to create the state of the contract, the caller is
+    // the first admin
+    pub fn new(caller: ActorId) -> Self {
+        Self {
+            last_who_call: (caller, PingEnum::Ping),
+            all_calls: CallHistory::default(),
+            call_counters: CallCounters::default(),
+            admins: vec![caller],
+            trusted_proxies: Vec::new()
         }
     }
 
-    // Get the contract state Ping state as mut
-    pub fn state_mut() -> &'static mut PingState {
-        let state = unsafe { PING_STATE.as_mut() };
-        debug_assert!(state.is_some(), "State is not initialized");
-        unsafe { state.unwrap_unchecked() }
+    pub fn is_admin(&self, address: ActorId) -> bool {
+        self.admins.contains(&address)
     }
 
-    // Get the contract state Ping state as ref
-    pub fn state_ref() -> &'static PingState {
-        let state = unsafe { PING_STATE.as_ref() };
-        debug_assert!(state.is_some(), "State is not initialized");
-        unsafe { state.unwrap_unchecked() }
+    pub fn is_trusted_proxy(&self, address: ActorId) -> bool {
+        self.trusted_proxies.contains(&address)
     }
 }
 
-#[derive(Encode, Decode, TypeInfo, Clone)]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Debug)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum PingEnum {
@@ -0,0 +1,166 @@
+// Unit tests of the call counters and the leaderboard, they run on the host
+// with "cargo test"
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+use app::states::{
+    call_counters_state::{
+        ActorCalls,
+        CallCounters,
+        CallTotals,
+        MAX_LEADERBOARD_SIZE,
+        MAX_TRACKED_ACTORS
+    },
+    ping_pong_state::PingEnum
+};
+
+fn calls(pings: u64, pongs: u64) -> ActorCalls {
+    ActorCalls { pings, pongs }
+}
+
+#[test]
+fn calls_are_counted_by_actor() {
+    let mut counters = CallCounters::default();
+
+    counters.record(1u64.into(), &PingEnum::Ping);
+    counters.record(1u64.into(), &PingEnum::Pong);
+    counters.record(2u64.into(), &PingEnum::Ping);
+
+    assert_eq!(counters.actor(1u64.into()), Some(calls(1, 1)));
+    assert_eq!(counters.actor(2u64.into()), Some(calls(1, 0)));
+    assert_eq!(counters.actor(3u64.into()), None);
+    assert_eq!(counters.totals(), CallTotals { pings: 2, pongs: 1, unique_callers: 2, evicted_callers: 0 });
+}
+
+#[test]
+fn leaderboard_is_sorted_by_total_calls() {
+    let mut counters = CallCounters::default();
+
+    // The actor 2 reaches 1 call before the actor 3, so it goes first on the tie
+    for actor in [1u64, 2, 1, 3, 4, 4, 1] {
+        counters.record(actor.into(), &PingEnum::Ping);
+    }
+
+    assert_eq!(counters.leaderboard(10), vec![
+        (ActorId::from(1u64), calls(3, 0)),
+        (ActorId::from(4u64), calls(2, 0)),
+        (ActorId::from(2u64), calls(1, 0)),
+        (ActorId::from(3u64), calls(1, 0))
+    ]);
+    assert_eq!(counters.leaderboard(2).len(), 2);
+    assert!(counters.leaderboard(0).is_empty());
+}
+
+// The leaderboard that is updated with each call is the same as sorting all the
+// actors by their total calls (and by the call in which they reached it on ties)
+#[test]
+fn leaderboard_is_the_same_as_sorting_all_the_actors() {
+    let mut counters = CallCounters::default();
+    // Total calls of each actor and the call in which it reached it
+    let mut expected: BTreeMap<u64, (u64, u64)> = BTreeMap::new();
+    let mut seed: u64 = 42;
+
+    for call in 0..5_000u64 {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let actor = (seed >> 33) % 200;
+
+        counters.record(actor.into(), &PingEnum::Pong);
+
+        let (total, reached_at) = expected.entry(actor).or_default();
+        *total += 1;
+        *reached_at = call;
+    }
+
+    let mut actors: Vec<(u64, (u64, u64))> = expected.into_iter().collect();
+    actors.sort_by_key(|(_, (total, reached_at))| (u64::MAX - total, *reached_at));
+
+    let leaderboard: Vec<(u64, u64)> = actors
+        .into_iter()
+        .take(MAX_LEADERBOARD_SIZE as usize)
+        .map(|(actor, (total, _))| (actor, total))
+        .collect();
+
+    assert_eq!(
+        counters.leaderboard(u32::MAX),
+        leaderboard
+            .into_iter()
+            .map(|(actor, total)| (ActorId::from(actor), calls(0, total)))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn actors_with_less_calls_are_removed_when_there_are_too_many() {
+    let mut counters = CallCounters::default();
+
+    // The actor 0 has the most calls, the actor 1 the least
+    for _ in 0..3 {
+        counters.record(0u64.into(), &PingEnum::Ping);
+    }
+    for actor in 1..MAX_TRACKED_ACTORS as u64 {
+        counters.record(actor.into(), &PingEnum::Ping);
+
+        if actor != 1 {
+            counters.record(actor.into(), &PingEnum::Pong);
+        }
+    }
+
+    let new_actor = MAX_TRACKED_ACTORS as u64;
+    counters.record(new_actor.into(), &PingEnum::Ping);
+
+    assert_eq!(counters.actor(1u64.into()), None);
+    assert_eq!(counters.actor(new_actor.into()), Some(calls(1, 0)));
+    assert_eq!(counters.leaderboard(1), vec![(ActorId::from(0u64), calls(3, 0))]);
+    // The totals still count the calls and the callers of the removed actors
+    assert_eq!(counters.totals(), CallTotals {
+        pings: MAX_TRACKED_ACTORS as u64 + 3,
+        pongs: MAX_TRACKED_ACTORS as u64 - 2,
+        unique_callers: MAX_TRACKED_ACTORS as u64 + 1,
+        evicted_callers: 1
+    });
+}
+
+// An actor whose counters were removed starts from zero if it calls again, and it
+// is counted again in the unique callers
+#[test]
+fn removed_actors_are_counted_again() {
+    let mut counters = CallCounters::default();
+
+    for actor in 0..=MAX_TRACKED_ACTORS as u64 {
+        counters.record(actor.into(), &PingEnum::Ping);
+    }
+
+    // The last actor that reached 1 call before the new actor is removed
+    let removed_actor = MAX_TRACKED_ACTORS as u64 - 1;
+    assert_eq!(counters.actor(removed_actor.into()), None);
+
+    counters.record(removed_actor.into(), &PingEnum::Pong);
+
+    assert_eq!(counters.actor(removed_actor.into()), Some(calls(0, 1)));
+    assert_eq!(counters.totals().unique_callers, MAX_TRACKED_ACTORS as u64 + 2);
+    assert_eq!(counters.totals().evicted_callers, 2);
+}
+
+// The actors in the leaderboard are not removed, even if they have the least calls
+#[test]
+fn actors_in_the_leaderboard_are_not_removed() {
+    let mut counters = CallCounters::default();
+
+    for actor in 0..MAX_TRACKED_ACTORS as u64 {
+        counters.record(actor.into(), &PingEnum::Ping);
+    }
+
+    counters.record((MAX_TRACKED_ACTORS as u64).into(), &PingEnum::Ping);
+
+    // The leaders are the first actors that reached 1 call
+    assert_eq!(
+        counters.leaderboard(u32::MAX),
+        (0..MAX_LEADERBOARD_SIZE as u64)
+            .map(|actor| (ActorId::from(actor), calls(1, 0)))
+            .collect::<Vec<_>>()
+    );
+    for (actor, _) in counters.leaderboard(u32::MAX) {
+        assert_eq!(counters.actor(actor), Some(calls(1, 0)));
+    }
+}
@@ -0,0 +1,180 @@
+// Unit tests of the history of calls, they run on the host with "cargo test"
+use app::states::{
+    call_history_state::{
+        CallHistory,
+        CallsPage,
+        HistoryInfo,
+        MAX_PAGE_SIZE
+    },
+    ping_pong_state::{
+        PingEnum,
+        UserData
+    }
+};
+use std::num::NonZeroU32;
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
+
+// Returns the call of the actor, the pings and pongs alternate so each call is
+// different from the previous one
+fn call(actor: u64, sequence: u64) -> UserData {
+    let action = if sequence.is_multiple_of(2) { PingEnum::Ping } else { PingEnum::Pong };
+
+    (actor.into(), action)
+}
+
+// Returns a history with the given calls, the actors of the calls alternate
+fn history(capacity: u32, calls: u64) -> (CallHistory, Vec<UserData>) {
+    let mut history = CallHistory::new(NonZeroU32::new(capacity).unwrap());
+    let calls: Vec<UserData> = (0..calls)
+        .map(|sequence| call(if sequence.is_multiple_of(2) { ALICE } else { BOB }, sequence))
+        .collect();
+
+    calls
+        .iter()
+        .for_each(|call| history.push(call.clone()));
+
+    (history, calls)
+}
+
+#[test]
+fn oldest_calls_are_replaced_when_the_history_is_full() {
+    let (mut history, mut calls) = history(3, 3);
+
+    assert_eq!(history.calls(), calls);
+    assert_eq!(history.info(), HistoryInfo { capacity: 3, len: 3, evicted: 0 });
+
+    // The writes wrap around the buffer more than once
+    for sequence in 3..8 {
+        calls.push(call(CHARLIE, sequence));
+        history.push(call(CHARLIE, sequence));
+
+        assert_eq!(history.calls(), calls[calls.len() - 3..].to_vec());
+    }
+
+    assert_eq!(history.info(), HistoryInfo { capacity: 3, len: 3, evicted: 5 });
+}
+
+#[test]
+fn smaller_capacity_keeps_the_newest_calls() {
+    // The buffer has wrapped around, the calls 2 to 6 are stored
+    let (mut history, calls) = history(5, 7);
+
+    history.set_capacity(NonZeroU32::new(2).unwrap());
+
+    assert_eq!(history.calls(), calls[5..].to_vec());
+    assert_eq!(history.info(), HistoryInfo { capacity: 2, len: 2, evicted: 5 });
+
+    // The evicted calls are counted, so the offsets keep pointing to the same calls
+    let page = history.page(5, 1, None);
+    assert_eq!(page, CallsPage { calls: calls[5..6].to_vec(), next_offset: Some(6) });
+}
+
+#[test]
+fn bigger_capacity_keeps_all_the_calls() {
+    let (mut history, mut calls) = history(3, 5);
+
+    history.set_capacity(NonZeroU32::new(4).unwrap());
+    assert_eq!(history.calls(), calls[2..].to_vec());
+    assert_eq!(history.info(), HistoryInfo { capacity: 4, len: 3, evicted: 2 });
+
+    // The new call fills the free slot, the next one evicts the oldest call
+    for sequence in 5..7 {
+        calls.push(call(ALICE, sequence));
+        history.push(call(ALICE, sequence));
+    }
+
+    assert_eq!(history.calls(), calls[3..].to_vec());
+    assert_eq!(history.info(), HistoryInfo { capacity: 4, len: 4, evicted: 3 });
+}
+
+#[test]
+fn pages_are_read_with_the_next_offset() {
+    let (history, calls) = history(10, 7);
+
+    let page = history.page(0, 3, None);
+    assert_eq!(page, CallsPage { calls: calls[..3].to_vec(), next_offset: Some(3) });
+
+    let page = history.page(3, 3, None);
+    assert_eq!(page, CallsPage { calls: calls[3..6].to_vec(), next_offset: Some(6) });
+
+    let page = history.page(6, 3, None);
+    assert_eq!(page, CallsPage { calls: calls[6..].to_vec(), next_offset: None });
+
+    let page = history.page(7, 3, None);
+    assert_eq!(page, CallsPage { calls: Vec::new(), next_offset: None });
+}
+
+#[test]
+fn pages_are_limited_to_the_max_page_size() {
+    let (history, calls) = history(100, 60);
+
+    let page = history.page(0, u32::MAX, None);
+    assert_eq!(page.calls, calls[..MAX_PAGE_SIZE as usize].to_vec());
+    assert_eq!(page.next_offset, Some(MAX_PAGE_SIZE as u64));
+}
+
+#[test]
+fn pages_of_an_actor_skip_the_calls_of_others() {
+    let (history, calls) = history(10, 7);
+    let alice_calls: Vec<UserData> = calls
+        .iter()
+        .step_by(2)
+        .cloned()
+        .collect();
+
+    // The calls of Alice are 0, 2, 4 and 6, so the next page starts at the call 4
+    let page = history.page(0, 2, Some(ALICE.into()));
+    assert_eq!(page, CallsPage { calls: alice_calls[..2].to_vec(), next_offset: Some(4) });
+
+    let page = history.page(4, 2, Some(ALICE.into()));
+    assert_eq!(page, CallsPage { calls: alice_calls[2..].to_vec(), next_offset: None });
+
+    let page = history.page(0, 10, Some(CHARLIE.into()));
+    assert_eq!(page, CallsPage { calls: Vec::new(), next_offset: None });
+}
+
+// The offsets are sequence numbers, so a page read after the oldest calls were
+// evicted continues from the same call instead of skipping calls
+#[test]
+fn offsets_keep_pointing_to_the_same_call_after_evictions() {
+    let (mut history, mut calls) = history(5, 5);
+
+    let page = history.page(0, 2, None);
+    assert_eq!(page, CallsPage { calls: calls[..2].to_vec(), next_offset: Some(2) });
+
+    // The calls 0 and 1 are evicted
+    for sequence in 5..7 {
+        calls.push(call(ALICE, sequence));
+        history.push(call(ALICE, sequence));
+    }
+
+    let page = history.page(2, 2, None);
+    assert_eq!(page, CallsPage { calls: calls[2..4].to_vec(), next_offset: Some(4) });
+
+    let page = history.page(4, 5, None);
+    assert_eq!(page, CallsPage { calls: calls[4..].to_vec(), next_offset: None });
+
+    // The page of an evicted call starts with the oldest stored call
+    let page = history.page(0, 1, None);
+    assert_eq!(page, CallsPage { calls: calls[2..3].to_vec(), next_offset: Some(3) });
+}
+
+#[test]
+fn pages_of_an_actor_are_read_across_the_wrap_around() {
+    let (history, calls) = history(4, 10);
+
+    // The history has the calls 6 to 9, Alice made the calls 6 and 8
+    assert_eq!(history.calls(), calls[6..].to_vec());
+
+    let page = history.page(0, 1, Some(ALICE.into()));
+    assert_eq!(page, CallsPage { calls: vec![calls[6].clone()], next_offset: Some(8) });
+
+    let page = history.page(8, 1, Some(ALICE.into()));
+    assert_eq!(page, CallsPage { calls: vec![calls[8].clone()], next_offset: None });
+
+    let page = history.page(7, 10, Some(BOB.into()));
+    assert_eq!(page, CallsPage { calls: vec![calls[7].clone(), calls[9].clone()], next_offset: None });
+}
@@ -1,6 +1,9 @@
 use sails_client_gen::ClientGenerator;
 use std::{env, fs, path::PathBuf};
-use app::PingProgram;
+use app::{
+    PingProgram,
+    program_info
+};
 
 fn main() {
     // Build contract to get .opt.wasm
@@ -23,6 +26,21 @@ fn main() {
     sails_idl_gen::generate_idl_to_file::<PingProgram>(idl_path.clone())
         .unwrap();
 
+    // The app embeds the info of the committed IDL (see app/build.rs), the build
+    // fails if the IDL changed in this build, so the info is never stale
+    build_info::check_idl(&idl_path, program_info::IDL_HASH);
+
+    // Compare the IDL with the last released one (app.released.idl), the build fails
+    // if it has breaking changes that are not acknowledged in idl_breaking_changes.txt
+    let released_idl_path = cargo_toml_path.join("app.released.idl");
+    let acknowledged_path = cargo_toml_path.join("idl_breaking_changes.txt");
+    println!("cargo:rerun-if-changed={}", released_idl_path.display());
+    println!("cargo:rerun-if-changed={}", acknowledged_path.display());
+
+    if let Err(error) = idl_compat::check_files(&released_idl_path, &idl_path, Some(&acknowledged_path)) {
+        panic!("{error}");
+    }
+
     // Generator of the clients of the contract
     ClientGenerator::from_idl_path(&idl_path)
         .generate_to(client_path.clone())
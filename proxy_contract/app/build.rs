@@ -0,0 +1,1116 @@
+use sails_client_gen::ClientGenerator;
+use sails_idl_parser::ast::{self, PrimitiveType, TypeDecl, TypeDef, TypeId};
+use std::{env, fs, path::{Path, PathBuf}};
+
+// If this environment variable is set, the clients in "src/clients" are
+// updated with the generated ones instead of failing the build
+const UPDATE_CLIENTS_ENV: &str = "PROXY_UPDATE_CLIENTS";
+
+// Contracts that the proxy can call, the build script reads the IDL of each one
+// and generates its client module, its caller service, the proxy routes and the
+// routes of the program, so adding a new target contract only takes a new entry
+// here (and its feature in Cargo.toml). The targets and their commands are encoded
+// by their position (ProxyTarget and ProxyRoute), so new ones are added at the end.
+// - name: name of the service in the IDL of the contract, it is used to name the
+//   generated types (Proxy{name}CallerService, Proxy{name}CallerEvent, etc).
+// - feature: cargo feature that compiles the target in the proxy.
+// - idl: path of the IDL of the contract (relative to this file), its client is
+//   generated in each build and compared with "src/clients/{name}_client.rs".
+// - module: name of the module of the caller service (in "services").
+// - commands: commands that are proxied, the contract must have a command
+//   "{command}OnBehalfOf" that receives the user as its first argument (sent
+//   once the contract trusts the proxy) and the plain command (sent before).
+// - events: variants of the event of the caller service (Proxy{name}CallerEvent)
+//   in the order of their indexes, the proxied queries are taken from them. The
+//   released variants can't be moved or renamed, new ones are added at the end.
+const TARGETS: &[Target] = &[
+    Target {
+        name: "Ping",
+        feature: "ping-caller",
+        idl: "../../ping_pong_contract/wasm/app.idl",
+        module: "proxy_ping_caller_service",
+        commands: &["Ping", "Pong"],
+        events: &[
+            Event::Unit("Test"),
+            Event::Query("AllCalls"),
+            Event::Query("LastWhoCall"),
+            Event::Response,
+            Event::Error,
+            Event::Query("CallsPage"),
+            Event::Query("CallsByActor"),
+            Event::Query("CallsCount")
+        ]
+    },
+    Target {
+        name: "TrafficLight",
+        feature: "traffic-light-caller",
+        idl: "../../traffic_light_contract/wasm/app.idl",
+        module: "proxy_taffic_light_caller_service",
+        commands: &["Green", "Yellow", "Red"],
+        events: &[
+            Event::Unit("Test"),
+            Event::Response,
+            Event::RenamedQuery {
+                query: "TrafficLight",
+                variant: "State",
+                method: "TrafficLightState"
+            },
+            Event::Error
+        ]
+    }
+];
+
+struct Target {
+    name: &'static str,
+    feature: &'static str,
+    idl: &'static str,
+    module: &'static str,
+    commands: &'static [&'static str],
+    events: &'static [Event]
+}
+
+// Variant of the event of a caller service
+enum Event {
+    // Variant without data (like the "Test" variant of the first release)
+    Unit(&'static str),
+    // Response of the proxied commands, "{name}ContractResponse"
+    Response,
+    // Response of a proxied query, "{name}ContractState{query}", the query is
+    // sent with the "Call{query}" method of the caller service
+    Query(&'static str),
+    // Same as "Query", but the variant is "{name}Contract{variant}" and the
+    // method is "Call{method}" (the names of the first release)
+    RenamedQuery {
+        query: &'static str,
+        variant: &'static str,
+        method: &'static str
+    },
+    // Errors of the caller service, "Error(Proxy{name}CallerError)"
+    Error
+}
+
+// Function of a service from an IDL
+struct IdlFunc {
+    name: String,
+    params: Vec<(String, String)>,
+    output: String,
+    is_query: bool
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={UPDATE_CLIENTS_ENV}");
+
+    let targets = enabled_targets();
+
+    if targets.is_empty() {
+        panic!(
+            "Enable at least one of the caller features: {}",
+            TARGETS.iter().map(|target| target.feature).collect::<Vec<_>>().join(", ")
+        );
+    }
+
+    for target in &targets {
+        let idl_path = manifest_dir.join(target.idl);
+        println!("cargo:rerun-if-changed={}", idl_path.display());
+
+        let idl = fs::read_to_string(&idl_path)
+            .unwrap_or_else(|error| panic!("Can't read the IDL {}: {error}", idl_path.display()));
+        let funcs = parse_service(&idl, target.name);
+
+        check_client(
+            &idl_path,
+            &manifest_dir.join(format!("src/clients/{}_client.rs", snake_case(target.name))),
+            &out_dir
+        );
+
+        fs::write(
+            out_dir.join(format!("{}.rs", target.module)),
+            caller_service(target, &funcs)
+        ).unwrap();
+    }
+
+    fs::write(out_dir.join("proxy_route.rs"), proxy_route(&targets)).unwrap();
+    fs::write(out_dir.join("proxy_clients.rs"), proxy_clients(&targets, &manifest_dir)).unwrap();
+    fs::write(out_dir.join("caller_services.rs"), caller_services(&targets)).unwrap();
+    fs::write(out_dir.join("proxy_program.rs"), proxy_program(&targets)).unwrap();
+
+    // The IDL of the proxy is generated by the wasm build, so the info uses the committed
+    // IDL (the wasm build fails if it changes), builds without all the targets use their
+    // own IDL (see "idl_path")
+    let idl_path = idl_path(&targets);
+
+    fs::write(
+        out_dir.join("program_info.rs"),
+        format!(
+            "pub const IDL_PATH: &str = {idl_path:?};\n{}",
+            build_info::program_info(&manifest_dir.join("../wasm").join(&idl_path))
+        )
+    ).unwrap();
+}
+
+// Returns the targets whose features are enabled
+fn enabled_targets() -> Vec<&'static Target> {
+    TARGETS
+        .iter()
+        .filter(|target| {
+            let feature_env = format!("CARGO_FEATURE_{}", target.feature.to_uppercase().replace('-', "_"));
+            env::var_os(feature_env).is_some()
+        })
+        .collect()
+}
+
+// Returns the path of the IDL (relative to the wasm directory), the builds with all
+// the targets use "app.idl", the others use a directory named by their features
+// (like "ping-caller/app.idl"), so the committed IDL is not changed
+fn idl_path(targets: &[&Target]) -> String {
+    if targets.len() == TARGETS.len() {
+        return "app.idl".to_string();
+    }
+
+    let features: Vec<&str> = targets.iter().map(|target| target.feature).collect();
+
+    format!("{}/app.idl", features.join("+"))
+}
+
+// Generates the client of the IDL and compares it with the committed client, the
+// build fails with the diff if they are different (the client is stale)
+fn check_client(idl_path: &Path, client_path: &Path, out_dir: &Path) {
+    let generated_path = out_dir.join(client_path.file_name().unwrap());
+    println!("cargo:rerun-if-changed={}", client_path.display());
+
+    ClientGenerator::from_idl_path(idl_path)
+        .generate_to(&generated_path)
+        .unwrap();
+
+    let generated = fs::read_to_string(&generated_path).unwrap();
+    let committed = fs::read_to_string(client_path).unwrap_or_default();
+
+    if generated == committed {
+        return;
+    }
+
+    if env::var_os(UPDATE_CLIENTS_ENV).is_some() {
+        fs::write(client_path, generated).unwrap();
+        return;
+    }
+
+    panic!(
+        "The client {} is different from the client generated from {}:\n\n{}\n\
+        Run the build with {UPDATE_CLIENTS_ENV}=1 to update it.",
+        client_path.display(),
+        idl_path.display(),
+        diff(&committed, &generated)
+    );
+}
+
+// Returns the lines that were removed (-) and added (+) from "old" to "new"
+fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence of the lines, lcs[i][j] is the length for old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            diff.push_str(&format!("{:>5} + {}\n", j + 1, new[j]));
+            j += 1;
+        } else {
+            diff.push_str(&format!("{:>5} - {}\n", i + 1, old[i]));
+            i += 1;
+        }
+    }
+
+    diff
+}
+
+// Returns the functions of the service "name" from the IDL
+fn parse_service(idl: &str, name: &str) -> Vec<IdlFunc> {
+    let program = ast::parse_idl(idl)
+        .unwrap_or_else(|error| panic!("Can't parse the IDL of the {name} service: {error}"));
+
+    program
+        .services()
+        .iter()
+        .find(|service| service.name() == name)
+        .unwrap_or_else(|| panic!("Service {name} not found in the IDL"))
+        .funcs()
+        .iter()
+        .map(|func| IdlFunc {
+            name: func.name().to_string(),
+            params: func.params()
+                .iter()
+                .map(|param| (param.name().to_string(), rust_type(param.type_decl())))
+                .collect(),
+            output: rust_type(func.output()),
+            is_query: func.is_query()
+        })
+        .collect()
+}
+
+// Returns the rust type of an IDL type, the types declared in the IDL are
+// taken from the client of the contract ("target_client")
+fn rust_type(type_decl: &TypeDecl) -> String {
+    match type_decl {
+        TypeDecl::Optional(inner) => format!("Option<{}>", rust_type(inner)),
+        TypeDecl::Vector(inner) => format!("Vec<{}>", rust_type(inner)),
+        TypeDecl::Array { item, len } => format!("[{}; {len}]", rust_type(item)),
+        TypeDecl::Result { ok, err } => format!("Result<{}, {}>", rust_type(ok), rust_type(err)),
+        TypeDecl::Id(TypeId::Primitive(primitive)) => primitive_type(primitive).to_string(),
+        TypeDecl::Id(TypeId::UserDefined(name)) => format!("target_client::{name}"),
+        // The structs without field names are tuples in the clients
+        TypeDecl::Def(TypeDef::Struct(def)) if def.fields().iter().all(|field| field.name().is_none()) => {
+            let fields: Vec<String> = def.fields()
+                .iter()
+                .map(|field| rust_type(field.type_decl()))
+                .collect();
+
+            match fields.len() {
+                1 => format!("({},)", fields[0]),
+                _ => format!("({})", fields.join(", "))
+            }
+        },
+        _ => panic!("The IDL type {type_decl:?} can't be used in the proxied methods")
+    }
+}
+
+fn primitive_type(primitive: &PrimitiveType) -> &'static str {
+    match primitive {
+        PrimitiveType::Null => "()",
+        PrimitiveType::Bool => "bool",
+        PrimitiveType::Char => "char",
+        PrimitiveType::Str => "String",
+        PrimitiveType::U8 => "u8",
+        PrimitiveType::U16 => "u16",
+        PrimitiveType::U32 => "u32",
+        PrimitiveType::U64 => "u64",
+        PrimitiveType::U128 => "u128",
+        PrimitiveType::I8 => "i8",
+        PrimitiveType::I16 => "i16",
+        PrimitiveType::I32 => "i32",
+        PrimitiveType::I64 => "i64",
+        PrimitiveType::I128 => "i128",
+        PrimitiveType::ActorId => "ActorId",
+        PrimitiveType::CodeId => "CodeId",
+        PrimitiveType::MessageId => "MessageId",
+        PrimitiveType::H160 => "H160",
+        PrimitiveType::H256 => "H256",
+        PrimitiveType::U256 => "U256",
+        #[allow(unreachable_patterns)]
+        _ => panic!("The IDL type {primitive:?} can't be used in the proxied methods")
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+
+    snake
+}
+
+fn find_func<'a>(funcs: &'a [IdlFunc], target: &Target, name: &str, is_query: bool) -> &'a IdlFunc {
+    funcs
+        .iter()
+        .find(|func| func.name == name && func.is_query == is_query)
+        .unwrap_or_else(|| panic!(
+            "The {} service has no {} \"{name}\"",
+            target.name,
+            if is_query { "query" } else { "command" }
+        ))
+}
+
+// Generates the modules of the clients of the targets (from "src/clients") and
+// the struct that stores them in the program
+fn proxy_clients(targets: &[&Target], manifest_dir: &Path) -> String {
+    let modules: String = targets
+        .iter()
+        .map(|target| {
+            let module = format!("{}_client", snake_case(target.name));
+            let path = manifest_dir.join(format!("src/clients/{module}.rs"));
+
+            format!("pub mod {module} {{\n    include!({:?});\n}}\n\n", path.display().to_string())
+        })
+        .collect();
+    let fields: Vec<String> = targets
+        .iter()
+        .map(|target| format!(
+            "    pub {snake}_client: RefCell<{snake}_client::{name}<GStdRemoting>>",
+            snake = snake_case(target.name),
+            name = target.name
+        ))
+        .collect();
+    let values: Vec<String> = targets
+        .iter()
+        .map(|target| format!(
+            "            {snake}_client: RefCell::new({snake}_client::{name}::new(GStdRemoting))",
+            snake = snake_case(target.name),
+            name = target.name
+        ))
+        .collect();
+
+    format!(
+r#"// Code generated by the proxy build script (app/build.rs). DO NOT EDIT.
+use sails_rs::{{
+    cell::RefCell,
+    gstd::calls::GStdRemoting
+}};
+
+{modules}// Clients of the target contracts, they are stored in the program, this helps
+// to save tokens on gas fees in contracts communications
+pub struct ProxyClients {{
+{fields}
+}}
+
+impl Default for ProxyClients {{
+    fn default() -> Self {{
+        Self {{
+{values}
+        }}
+    }}
+}}
+"#,
+        fields = fields.join(",\n"),
+        values = values.join(",\n")
+    )
+}
+
+// Generates the modules of the caller services of the targets
+fn caller_services(targets: &[&Target]) -> String {
+    let modules: Vec<String> = targets
+        .iter()
+        .map(|target| format!(
+            "pub mod {module} {{\n    include!(concat!(env!(\"OUT_DIR\"), \"/{module}.rs\"));\n}}\n",
+            module = target.module
+        ))
+        .collect();
+
+    format!(
+        "// Code generated by the proxy build script (app/build.rs). DO NOT EDIT.\n\n{}",
+        modules.join("\n")
+    )
+}
+
+// Generates the program (included in lib.rs), its constructor with the ids of
+// the targets and the routes of their caller services depend on the targets
+fn proxy_program(targets: &[&Target]) -> String {
+    let params: Vec<String> = targets
+        .iter()
+        .map(|target| format!("{}_contract_id: ActorId", snake_case(target.name)))
+        .collect();
+    let contract_ids: Vec<String> = targets
+        .iter()
+        .map(|target| format!(
+            "            (ProxyTarget::{}Contract, {}_contract_id)",
+            target.name,
+            snake_case(target.name)
+        ))
+        .collect();
+    let routes: String = targets
+        .iter()
+        .map(|target| format!(
+r#"
+    #[route("{name}Caller")]
+    pub fn {snake}_caller_svc(&self) -> services::{module}::Proxy{name}CallerService<'_, clients::{snake}_client::{name}<GStdRemoting>> {{
+        services::{module}::Proxy{name}CallerService::new(
+            &self.proxy_state,
+            self.clients.{snake}_client.borrow_mut()
+        )
+    }}
+"#,
+            name = target.name,
+            snake = snake_case(target.name),
+            module = target.module
+        ))
+        .collect();
+
+    format!(
+r#"// Code generated by the proxy build script (app/build.rs). DO NOT EDIT.
+#[program]
+impl ProxyProgram {{
+    pub fn new() -> Self {{
+        Self::new_proxy(Vec::new())
+    }}
+
+    pub fn new_with_contracts_id(
+        {params}
+    ) -> Self {{
+        Self::new_proxy(vec![
+{contract_ids}
+        ])
+    }}
+{routes}
+    #[route("Proxy")]
+    pub fn proxy_svc(&self) -> ProxyService<'_> {{
+        ProxyService::new(self.proxy_state.borrow_mut())
+    }}
+
+    #[route("Credits")]
+    pub fn credits_svc(&self) -> ProxyCreditsService<'_> {{
+        ProxyCreditsService::new(self.proxy_state.borrow_mut())
+    }}
+
+    #[route("Relayer")]
+    pub fn relayer_svc(&self) -> ProxyRelayerService<'_> {{
+        ProxyRelayerService::new(
+            &self.proxy_state,
+            &self.clients
+        )
+    }}
+
+    #[route("Sessions")]
+    pub fn sessions_svc(&self) -> ProxySessionsService<'_> {{
+        ProxySessionsService::new(self.proxy_state.borrow_mut())
+    }}
+}}
+"#,
+        params = params.join(",\n        "),
+        contract_ids = contract_ids.join(",\n")
+    )
+}
+
+// Generates the enums of the targets and the routes of the proxy, they have all the
+// targets of TARGETS (also the ones that are not enabled), and their indexes are set
+// by their position in TARGETS, so they are encoded the same way in every build
+fn proxy_route(enabled_targets: &[&Target]) -> String {
+    let targets: Vec<String> = TARGETS
+        .iter()
+        .enumerate()
+        .map(|(index, target)| format!("    #[codec(index = {index})]\n    {}Contract", target.name))
+        .collect();
+    let routes: Vec<String> = TARGETS
+        .iter()
+        .flat_map(|target| target.commands.iter())
+        .enumerate()
+        .map(|(index, command)| format!("    #[codec(index = {index})]\n    Call{command}"))
+        .collect();
+    let target_enabled: Vec<String> = TARGETS
+        .iter()
+        .map(|target| format!(
+            "            ProxyTarget::{}Contract => {}",
+            target.name,
+            enabled_targets.iter().any(|enabled| enabled.name == target.name)
+        ))
+        .collect();
+    let route_targets: Vec<String> = TARGETS
+        .iter()
+        .map(|target| {
+            let routes: Vec<String> = target.commands
+                .iter()
+                .map(|command| format!("ProxyRoute::Call{command}"))
+                .collect();
+
+            format!("            {} => ProxyTarget::{}Contract", routes.join(" | "), target.name)
+        })
+        .collect();
+
+    format!(
+r#"// Code generated by the proxy build script (app/build.rs). DO NOT EDIT.
+
+// Enum to identify the contracts that the proxy can call
+#[derive(PartialEq, Clone, Copy, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyTarget {{
+{targets}
+}}
+
+impl ProxyTarget {{
+    // Returns if the caller service of the target is compiled in this build
+    pub fn is_enabled(&self) -> bool {{
+        match self {{
+{target_enabled}
+        }}
+    }}
+}}
+
+// Enum to identify each proxied command, it is used to store data
+// related to each method of the caller services (like metrics)
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRoute {{
+{routes}
+}}
+
+impl ProxyRoute {{
+    // Returns the contract that is called by the route
+    pub fn target(&self) -> ProxyTarget {{
+        match self {{
+{route_targets}
+        }}
+    }}
+}}
+"#,
+        targets = targets.join(",\n"),
+        target_enabled = target_enabled.join(",\n"),
+        routes = routes.join(",\n"),
+        route_targets = route_targets.join(",\n")
+    )
+}
+
+// Generates the caller service of a target contract
+fn caller_service(target: &Target, funcs: &[IdlFunc]) -> String {
+    let name = target.name;
+    let snake = snake_case(name);
+    let words = snake.replace('_', " ");
+
+    let mut response_type: Option<&str> = None;
+    let mut commands = String::new();
+    let mut commands_as = String::new();
+    let mut route_arms = String::new();
+
+    for command in target.commands {
+        let func = find_func(funcs, target, &format!("{command}OnBehalfOf"), false);
+
+        match func.params.first() {
+            Some((_, param_type)) if param_type == "ActorId" => {},
+            _ => panic!("{name}::{command}OnBehalfOf must receive the user as its first argument")
+        }
+
+        match response_type {
+            Some(output) if output != func.output => {
+                panic!("The proxied commands of the {name} service must return the same type")
+            },
+            _ => response_type = Some(func.output.as_str())
+        }
+
+        // The plain command is sent while the target does not trust the proxy
+        let plain_func = find_func(funcs, target, command, false);
+
+        if plain_func.params[..] != func.params[1..] || plain_func.output != func.output {
+            panic!("{name}::{command} must receive the same arguments as {command}OnBehalfOf (without the user)")
+        }
+
+        let method = snake_case(command);
+        let params = &func.params[1..];
+        let params_decl: String = params
+            .iter()
+            .map(|(param, param_type)| format!("{param}: {param_type}, "))
+            .collect();
+        let params_names: String = params
+            .iter()
+            .map(|(param, _)| format!("{param}, "))
+            .collect();
+        let command_params: String = params
+            .iter()
+            .map(|(param, param_type)| format!(", {param}: {param_type}"))
+            .collect();
+
+        commands.push_str(&format!(
+r#"
+    // Method (command) that will call the {method} method in the {words} contract
+    // It doesn't change the state from the proxy contract, but we need gas
+    // fees to be able to change the state of the {words} contract
+    pub async fn call_{method}(&mut self{command_params}) -> Proxy{name}CallerEvent {{
+        self.send_{method}({params_names}None).await
+    }}
+
+    // Same as "call_{method}", but the result is stored with the idempotency key, if
+    // the command is sent again with the key (before the TTL of the results), the
+    // stored result is returned and the command is not sent again
+    pub async fn call_{method}_idempotent(&mut self, {params_decl}idempotency_key: H256) -> Proxy{name}CallerEvent {{
+        self.send_{method}({params_names}Some(idempotency_key)).await
+    }}
+"#
+        ));
+
+        let call_args: Vec<&str> = ["user"]
+            .into_iter()
+            .chain(params.iter().map(|(param, _)| param.as_str()))
+            .collect();
+
+        commands_as.push_str(&format!(
+r#"
+    // Sends the command of "call_{method}" and "call_{method}_idempotent" for the caller
+    async fn send_{method}(&mut self, {params_decl}idempotency_key: Option<H256>) -> Proxy{name}CallerEvent {{
+        // If the caller is a session key, the command is sent as its owner
+        let user = match self.session_user(ProxyRoute::Call{command}) {{
+            Ok(user) => user,
+            Err(error_event) => return self.finish_call(ProxyRoute::Call{command}, msg::source(), 0, error_event)
+        }};
+
+        // Return the stored result if the command was already sent with the key,
+        // it is not counted as a call of the session key
+        if let Some(event) = self.stored_result(ProxyRoute::Call{command}, user, idempotency_key) {{
+            return event;
+        }}
+
+        let session_call = self.proxy_state
+            .borrow_mut()
+            .sessions
+            .record_call(msg::source());
+
+        if let Err(error) = session_call {{
+            let error_event = Proxy{name}CallerEvent::Error(Proxy{name}CallerError::SessionKey(error));
+            return self.finish_call(ProxyRoute::Call{command}, msg::source(), 0, error_event);
+        }}
+
+        self.call_{method}_as(user, {params_names}idempotency_key).await
+    }}
+
+    // Sends the command on behalf of the given user, who is used for all the
+    // authorization checks (rate limits, credit, etc), the result is stored with
+    // the idempotency key (the stored results are checked by the caller)
+    pub async fn call_{method}_as(
+        &mut self,
+        user: ActorId,
+        {params_decl}idempotency_key: Option<H256>
+    ) -> Proxy{name}CallerEvent {{
+        // Check if the user can send the command (fee and rate limit), the fee
+        // is paid with the credit of the user if no value was attached
+        let credit_charged = match self.check_call(ProxyRoute::Call{command}, user) {{
+            Err(error_event) => return self.finish_call(ProxyRoute::Call{command}, user, 0, error_event),
+            Ok(credit_charged) => credit_charged
+        }};
+
+        // Get the {words} contract id
+        let contract_id = match self.{snake}_contract_id() {{
+            Err(error_event) => return self.finish_call(ProxyRoute::Call{command}, user, credit_charged, error_event),
+            Ok(id) => id
+        }};
+
+        // Call the contract and store the response in a variable, the user is sent
+        // to the contract only if it trusts the proxy (if not, the proxy is the caller).
+        // The state is not borrowed while the reply is awaited
+        let on_behalf_of = self.proxy_state
+            .borrow()
+            .sends_on_behalf_of(ProxyTarget::{name}Contract);
+
+        let temp = if on_behalf_of {{
+            self.{snake}_client
+                .{method}_on_behalf_of({call_args}) // Method name to call, the user is sent to the contract
+                .send_recv(contract_id) // Send the message and get a response
+                .await
+        }} else {{
+            self.{snake}_client
+                .{method}({plain_args}) // Method name to call
+                .send_recv(contract_id) // Send the message and get a response
+                .await
+        }};
+
+        // Check if the contract response was successfull
+        let contract_response = match temp {{
+            Ok(response) => response,
+            Err(error) => return self.finish_call(
+                ProxyRoute::Call{command},
+                user,
+                credit_charged,
+                Proxy{name}CallerEvent::Error(
+                    Proxy{name}CallerError::{name}ContractError(error.to_string())
+                )
+            )
+        }};
+
+        let event = self.finish_call(
+            ProxyRoute::Call{command},
+            user,
+            credit_charged,
+            Proxy{name}CallerEvent::{name}ContractResponse(contract_response)
+        );
+
+        // Store the result to return it if the command is sent again with the key
+        self.store_result(ProxyRoute::Call{command}, user, idempotency_key, &event);
+
+        // Return the proxy contract event
+        event
+    }}
+"#,
+            call_args = call_args.join(", "),
+            plain_args = call_args[1..].join(", ")
+        ));
+
+        if params.is_empty() {
+            route_arms.push_str(&format!(
+                "            ProxyRoute::Call{command} => Some(self.call_{method}_as(user, idempotency_key).await),\n"
+            ));
+        }
+    }
+
+    let count = |is_kind: fn(&Event) -> bool| target.events.iter().filter(|event| is_kind(event)).count();
+
+    if count(|event| matches!(event, Event::Response)) != 1 || count(|event| matches!(event, Event::Error)) != 1 {
+        panic!("The events of the {name} target must have one Response and one Error variant")
+    }
+
+    let mut queries = String::new();
+    let mut event_variants = Vec::new();
+
+    for event in target.events {
+        let (query, variant, method) = match *event {
+            Event::Unit(variant) => {
+                event_variants.push(format!("    {variant}"));
+                continue;
+            },
+            Event::Response => {
+                let output = response_type
+                    .unwrap_or_else(|| panic!("The {name} target has no commands to get its response type"));
+                event_variants.push(format!("    {name}ContractResponse({output})"));
+                continue;
+            },
+            Event::Error => {
+                event_variants.push(format!("    Error(Proxy{name}CallerError)"));
+                continue;
+            },
+            Event::Query(query) => (query, format!("State{query}"), query),
+            Event::RenamedQuery { query, variant, method } => (query, variant.to_string(), method)
+        };
+
+        let func = find_func(funcs, target, query, true);
+        let client_method = snake_case(query);
+        let method = snake_case(method);
+        let params_decl: String = func.params
+            .iter()
+            .map(|(param, param_type)| format!(", {param}: {param_type}"))
+            .collect();
+        let params_names: Vec<&str> = func.params
+            .iter()
+            .map(|(param, _)| param.as_str())
+            .collect();
+
+        event_variants.push(format!("    {name}Contract{variant}({})", func.output));
+
+        queries.push_str(&format!(
+r#"
+    // Method (query) that will call the {client_method} method in the {words} contract
+    // This method calls the query method from {words} contract, and it will
+    // send the response to the user. It does not need gas fees
+    pub async fn call_{method}(&self{params_decl}) -> Proxy{name}CallerEvent {{
+        // Get the {words} contract id
+        let contract_id = match self.{snake}_contract_id() {{
+            Err(error_event) => return error_event,
+            Ok(id) => id
+        }};
+
+        // Call the contract query method and store the response un a variable
+        let temp = self.{snake}_client
+            .{client_method}({params_names}) // Method name to call
+            .recv(contract_id) // get the response from the contract
+            .await;
+
+        // Check if the contract response was successfull
+        let contract_state = match temp {{
+            Ok(response) => response,
+            Err(error) => return Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::{name}ContractError(error.to_string())
+            )
+        }};
+
+        // Return the proxy contract event
+        Proxy{name}CallerEvent::{name}Contract{variant}(contract_state)
+    }}
+"#,
+            params_names = params_names.join(", ")
+        ));
+    }
+
+    format!(
+r#"// Code generated by the proxy build script (app/build.rs) from the IDL of
+// the {words} contract. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::calls::{{Call, Query}};
+// Necesary crates
+use sails_rs::{{
+    prelude::*,
+    cell::{{
+        RefCell,
+        RefMut
+    }}
+}};
+// Import the syscalls (they are mocked in the tests)
+use crate::syscalls::{{
+    exec,
+    msg
+}};
+// Import the state, the proxy routes and the session key errors
+use crate::states::{{
+    proxy_state::ProxyState,
+    proxy_route::{{
+        ProxyRoute,
+        ProxyTarget
+    }},
+    session_state::SessionKeyError
+}};
+// Import the client of the {words} contract and its trait
+#[allow(unused_imports)]
+use crate::clients::{snake}_client::{{
+    self as target_client,
+    traits::{name}
+}};
+
+// Proxy {words} caller service struct to build the service, it contains the
+// RefCell of the state, that is only borrowed while the state is read or changed
+// (never while the reply of the contract is awaited, so the proxy can handle
+// other messages in the meantime), and a RefMut of the client.
+// It receives the client from the state ({snake}_client), this helps to save tokens
+// on gas fees in contracts communications
+pub struct Proxy{name}CallerService<'a, {name}Client> {{
+    proxy_state: &'a RefCell<ProxyState>,
+    {snake}_client: RefMut<'a, {name}Client>
+}}
+
+// Impl block that use a lifetime 'a and the generic type {name}Client, which have a restriction:
+// - The {name}Client should implement the {name} trait
+#[service]
+impl<'a, {name}Client> Proxy{name}CallerService<'a, {name}Client>
+where
+    {name}Client: {name}
+{{
+    // Related function to create a new instance of the service
+    pub const fn new(
+        proxy_state: &'a RefCell<ProxyState>,
+        {snake}_client: RefMut<'a, {name}Client>
+    ) -> Self {{
+        Self {{
+            proxy_state,
+            {snake}_client
+        }}
+    }}
+{commands}{queries}
+    // Helper method to get the user of the command, if the caller is a session
+    // key, the user will be its owner (only for the routes in its scope), the
+    // call is not counted in the session key
+    fn session_user(&mut self, route: ProxyRoute) -> Result<ActorId, Proxy{name}CallerEvent> {{
+        self.proxy_state
+            .borrow_mut()
+            .sessions
+            .resolve_user(msg::source(), route, exec::block_height())
+            .map_err(|error| Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::SessionKey(error)
+            ))
+    }}
+
+    // Helper method to check if the user can send a command to the route:
+    // - The message must have attached at least the fee of the route, or, if
+    //   no value was attached, the user must have enough credit to pay it.
+    // - The user must not exceed the rate limit of the route, if not, the
+    //   call is stored in the rate limit state.
+    // If no value was attached, the fee is charged from the credit before the
+    // call (it is returned by "finish_call" if the call fails), it returns the
+    // credit that was charged
+    fn check_call(&mut self, route: ProxyRoute, user: ActorId) -> Result<u128, Proxy{name}CallerEvent> {{
+        let mut proxy_state = self.proxy_state.borrow_mut();
+        let required = proxy_state.fees.fee(route);
+        let attached = msg::value();
+
+        if attached == 0 {{
+            let balance = proxy_state.credits.balance(user);
+
+            if balance < required {{
+                return Err(Proxy{name}CallerEvent::Error(
+                    Proxy{name}CallerError::InsufficientCredit {{ required, balance }}
+                ));
+            }}
+        }} else if attached < required {{
+            return Err(Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::InsufficientFee {{ required, attached }}
+            ));
+        }}
+
+        proxy_state
+            .rate_limits
+            .check_and_record(user, route, exec::block_height())
+            .map_err(|retry_after_block| Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::RateLimitExceeded {{ retry_after_block }}
+            ))?;
+
+        if attached > 0 {{
+            return Ok(0);
+        }}
+
+        proxy_state
+            .credits
+            .charge(user, required)
+            .map(|_| required)
+            .map_err(|balance| Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::InsufficientCredit {{ required, balance }}
+            ))
+    }}
+
+    // Helper method to finish a proxied command, it stores the metrics of the call
+    // and collects the fee of the route only if the call was successful (from the
+    // attached value or from the credit charged in "check_call"), the rest of the
+    // attached value is returned to the sender of the message and the credit is
+    // returned to the user if the call failed.
+    // It returns the same event to be sent as the response to the user
+    fn finish_call(
+        &mut self,
+        route: ProxyRoute,
+        user: ActorId,
+        credit_charged: u128,
+        event: Proxy{name}CallerEvent
+    ) -> Proxy{name}CallerEvent {{
+        let success = !matches!(event, Proxy{name}CallerEvent::Error(_));
+        let mut proxy_state = self.proxy_state.borrow_mut();
+
+        proxy_state
+            .metrics
+            .record(route, success, exec::block_height());
+        proxy_state.lock_import();
+
+        if !success && credit_charged > 0 {{
+            proxy_state.credits.deposit(user, credit_charged);
+        }}
+
+        let attached = msg::value();
+        let (charged, refund) = match (success, attached) {{
+            (false, attached) => (0, attached),
+            (true, 0) => (credit_charged, 0),
+            (true, attached) => {{
+                let charged = proxy_state.fees.fee(route).min(attached);
+                (charged, attached - charged)
+            }}
+        }};
+
+        proxy_state.fees.collect(charged);
+        drop(proxy_state);
+
+        self.refund(refund);
+
+        event
+    }}
+
+    // Helper method to get the result stored with the idempotency key of the user,
+    // the attached value is returned because the command is not sent again
+    fn stored_result(
+        &mut self,
+        route: ProxyRoute,
+        user: ActorId,
+        idempotency_key: Option<H256>
+    ) -> Option<Proxy{name}CallerEvent> {{
+        let proxy_state = self.proxy_state.borrow();
+        let stored = proxy_state
+            .idempotency
+            .get(user, idempotency_key?, exec::block_height())?;
+
+        let event = if stored.route != route {{
+            Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::IdempotencyKeyReused(stored.route)
+            )
+        }} else {{
+            Proxy{name}CallerEvent::decode(&mut stored.result.as_slice()).ok()?
+        }};
+        drop(proxy_state);
+
+        self.refund(msg::value());
+
+        Some(event)
+    }}
+
+    // Helper method to return value to the sender of the message, if the value
+    // can't be sent, it is added to the credit of the sender so it is not lost
+    fn refund(&mut self, amount: u128) {{
+        if amount > 0 && msg::send_bytes(msg::source(), [], amount).is_err() {{
+            self.proxy_state
+                .borrow_mut()
+                .credits
+                .deposit(msg::source(), amount);
+        }}
+    }}
+
+    // Helper method to store the result of a command sent with an idempotency key
+    fn store_result(
+        &mut self,
+        route: ProxyRoute,
+        user: ActorId,
+        idempotency_key: Option<H256>,
+        event: &Proxy{name}CallerEvent
+    ) {{
+        let Some(idempotency_key) = idempotency_key else {{
+            return;
+        }};
+
+        self.proxy_state
+            .borrow_mut()
+            .idempotency
+            .store(user, idempotency_key, route, event.encode(), exec::block_height());
+    }}
+
+    // Helper query method to get the {words} contract id
+    fn {snake}_contract_id(&self) -> Result<ActorId, Proxy{name}CallerEvent> {{
+        self.proxy_state
+            .borrow()
+            .contract_id(ProxyTarget::{name}Contract)
+            .ok_or(Proxy{name}CallerEvent::Error(
+                Proxy{name}CallerError::{name}ContractIdNotSet
+            ))
+    }}
+}}
+
+// Impl block with the methods that are not exposed by the service, they are
+// used by other services of the proxy (like the relayer)
+impl<'a, {name}Client> Proxy{name}CallerService<'a, {name}Client>
+where
+    {name}Client: {name}
+{{
+    // Sends the command of the route on behalf of the given user, it returns
+    // None if the route is not a command (without arguments) of this service
+    #[allow(unreachable_patterns)]
+    pub async fn call_route_as(
+        &mut self,
+        route: ProxyRoute,
+        user: ActorId,
+        idempotency_key: Option<H256>
+    ) -> Option<Proxy{name}CallerEvent> {{
+        match route {{
+{route_arms}            _ => None
+        }}
+    }}
+{commands_as}}}
+
+// Enum to set the events from the proxy {words} contract caller
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Proxy{name}CallerEvent {{
+{event_variants}
+}}
+
+// Enum to set the errors from the proxy {words} contract caller
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Proxy{name}CallerError {{
+    {name}ContractIdNotSet,
+    {name}ContractError(String),
+    RateLimitExceeded {{
+        retry_after_block: u32
+    }},
+    InsufficientFee {{
+        required: u128,
+        attached: u128
+    }},
+    InsufficientCredit {{
+        required: u128,
+        balance: u128
+    }},
+    SessionKey(SessionKeyError),
+    IdempotencyKeyReused(ProxyRoute)
+}}
+"#,
+        event_variants = event_variants.join(",\n")
+    )
+}
@@ -1,2 +1,4 @@
-pub mod ping_client;
-pub mod traffic_light_client;
\ No newline at end of file
+// The modules of the clients of the enabled targets ("{target}_client.rs" files
+// in this directory) and the ProxyClients struct are generated by the build
+// script (app/build.rs)
+include!(concat!(env!("OUT_DIR"), "/proxy_clients.rs"));
@@ -53,18 +53,87 @@ impl<R> Ping<R> {
 }
 impl<R: Remoting + Clone> traits::Ping for Ping<R> {
     type Args = R::Args;
+    fn add_trusted_proxy(
+        &mut self,
+        proxy: ActorId,
+    ) -> impl Call<Output = PingAdminEvent, Args = R::Args> {
+        RemotingAction::<_, ping::io::AddTrustedProxy>::new(self.remoting.clone(), proxy)
+    }
     fn ping(&mut self) -> impl Call<Output = PingEnum, Args = R::Args> {
         RemotingAction::<_, ping::io::Ping>::new(self.remoting.clone(), ())
     }
+    fn ping_on_behalf_of(&mut self, user: ActorId) -> impl Call<Output = PingEnum, Args = R::Args> {
+        RemotingAction::<_, ping::io::PingOnBehalfOf>::new(self.remoting.clone(), user)
+    }
     fn pong(&mut self) -> impl Call<Output = PingEnum, Args = R::Args> {
         RemotingAction::<_, ping::io::Pong>::new(self.remoting.clone(), ())
     }
+    fn pong_on_behalf_of(&mut self, user: ActorId) -> impl Call<Output = PingEnum, Args = R::Args> {
+        RemotingAction::<_, ping::io::PongOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn remove_trusted_proxy(
+        &mut self,
+        proxy: ActorId,
+    ) -> impl Call<Output = PingAdminEvent, Args = R::Args> {
+        RemotingAction::<_, ping::io::RemoveTrustedProxy>::new(self.remoting.clone(), proxy)
+    }
+    fn set_history_capacity(
+        &mut self,
+        capacity: u32,
+    ) -> impl Call<Output = PingAdminEvent, Args = R::Args> {
+        RemotingAction::<_, ping::io::SetHistoryCapacity>::new(self.remoting.clone(), capacity)
+    }
+    fn actor_calls(
+        &self,
+        actor: ActorId,
+    ) -> impl Query<Output = Option<ActorCalls>, Args = R::Args> {
+        RemotingAction::<_, ping::io::ActorCalls>::new(self.remoting.clone(), actor)
+    }
     fn all_calls(&self) -> impl Query<Output = Vec<(ActorId, PingEnum)>, Args = R::Args> {
         RemotingAction::<_, ping::io::AllCalls>::new(self.remoting.clone(), ())
     }
+    fn call_totals(&self) -> impl Query<Output = CallTotals, Args = R::Args> {
+        RemotingAction::<_, ping::io::CallTotals>::new(self.remoting.clone(), ())
+    }
+    fn calls_by_actor(
+        &self,
+        actor: ActorId,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = CallsPage, Args = R::Args> {
+        RemotingAction::<_, ping::io::CallsByActor>::new(
+            self.remoting.clone(),
+            (actor, offset, limit),
+        )
+    }
+    fn calls_count(&self) -> impl Query<Output = u32, Args = R::Args> {
+        RemotingAction::<_, ping::io::CallsCount>::new(self.remoting.clone(), ())
+    }
+    fn calls_page(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = CallsPage, Args = R::Args> {
+        RemotingAction::<_, ping::io::CallsPage>::new(self.remoting.clone(), (offset, limit))
+    }
+    fn history_info(&self) -> impl Query<Output = HistoryInfo, Args = R::Args> {
+        RemotingAction::<_, ping::io::HistoryInfo>::new(self.remoting.clone(), ())
+    }
+    fn info(&self) -> impl Query<Output = ProgramInfo, Args = R::Args> {
+        RemotingAction::<_, ping::io::Info>::new(self.remoting.clone(), ())
+    }
     fn last_who_call(&self) -> impl Query<Output = (ActorId, PingEnum), Args = R::Args> {
         RemotingAction::<_, ping::io::LastWhoCall>::new(self.remoting.clone(), ())
     }
+    fn leaderboard(
+        &self,
+        limit: u32,
+    ) -> impl Query<Output = Vec<(ActorId, ActorCalls)>, Args = R::Args> {
+        RemotingAction::<_, ping::io::Leaderboard>::new(self.remoting.clone(), limit)
+    }
+    fn trusted_proxies(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, ping::io::TrustedProxies>::new(self.remoting.clone(), ())
+    }
 }
 
 pub mod ping {
@@ -73,6 +142,21 @@ pub mod ping {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AddTrustedProxy(());
+        impl AddTrustedProxy {
+            #[allow(dead_code)]
+            pub fn encode_call(proxy: ActorId) -> Vec<u8> {
+                <AddTrustedProxy as ActionIo>::encode_call(&proxy)
+            }
+        }
+        impl ActionIo for AddTrustedProxy {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 60, 65, 100, 100, 84, 114, 117, 115, 116, 101, 100, 80, 114,
+                111, 120, 121,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingAdminEvent;
+        }
         pub struct Ping(());
         impl Ping {
             #[allow(dead_code)]
@@ -85,6 +169,21 @@ pub mod ping {
             type Params = ();
             type Reply = super::PingEnum;
         }
+        pub struct PingOnBehalfOf(());
+        impl PingOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <PingOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for PingOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 56, 80, 105, 110, 103, 79, 110, 66, 101, 104, 97, 108, 102,
+                79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingEnum;
+        }
         pub struct Pong(());
         impl Pong {
             #[allow(dead_code)]
@@ -97,6 +196,65 @@ pub mod ping {
             type Params = ();
             type Reply = super::PingEnum;
         }
+        pub struct PongOnBehalfOf(());
+        impl PongOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <PongOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for PongOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 56, 80, 111, 110, 103, 79, 110, 66, 101, 104, 97, 108, 102,
+                79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingEnum;
+        }
+        pub struct RemoveTrustedProxy(());
+        impl RemoveTrustedProxy {
+            #[allow(dead_code)]
+            pub fn encode_call(proxy: ActorId) -> Vec<u8> {
+                <RemoveTrustedProxy as ActionIo>::encode_call(&proxy)
+            }
+        }
+        impl ActionIo for RemoveTrustedProxy {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 72, 82, 101, 109, 111, 118, 101, 84, 114, 117, 115, 116,
+                101, 100, 80, 114, 111, 120, 121,
+            ];
+            type Params = ActorId;
+            type Reply = super::PingAdminEvent;
+        }
+        pub struct SetHistoryCapacity(());
+        impl SetHistoryCapacity {
+            #[allow(dead_code)]
+            pub fn encode_call(capacity: u32) -> Vec<u8> {
+                <SetHistoryCapacity as ActionIo>::encode_call(&capacity)
+            }
+        }
+        impl ActionIo for SetHistoryCapacity {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 72, 83, 101, 116, 72, 105, 115, 116, 111, 114, 121, 67, 97,
+                112, 97, 99, 105, 116, 121,
+            ];
+            type Params = u32;
+            type Reply = super::PingAdminEvent;
+        }
+        pub struct ActorCalls(());
+        impl ActorCalls {
+            #[allow(dead_code)]
+            pub fn encode_call(actor: ActorId) -> Vec<u8> {
+                <ActorCalls as ActionIo>::encode_call(&actor)
+            }
+        }
+        impl ActionIo for ActorCalls {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 40, 65, 99, 116, 111, 114, 67, 97, 108, 108, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Option<super::ActorCalls>;
+        }
         pub struct AllCalls(());
         impl AllCalls {
             #[allow(dead_code)]
@@ -111,6 +269,88 @@ pub mod ping {
             type Params = ();
             type Reply = Vec<(ActorId, super::PingEnum)>;
         }
+        pub struct CallTotals(());
+        impl CallTotals {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CallTotals as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CallTotals {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 40, 67, 97, 108, 108, 84, 111, 116, 97, 108, 115,
+            ];
+            type Params = ();
+            type Reply = super::CallTotals;
+        }
+        pub struct CallsByActor(());
+        impl CallsByActor {
+            #[allow(dead_code)]
+            pub fn encode_call(actor: ActorId, offset: u64, limit: u32) -> Vec<u8> {
+                <CallsByActor as ActionIo>::encode_call(&(actor, offset, limit))
+            }
+        }
+        impl ActionIo for CallsByActor {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 48, 67, 97, 108, 108, 115, 66, 121, 65, 99, 116, 111, 114,
+            ];
+            type Params = (ActorId, u64, u32);
+            type Reply = super::CallsPage;
+        }
+        pub struct CallsCount(());
+        impl CallsCount {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CallsCount as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CallsCount {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 40, 67, 97, 108, 108, 115, 67, 111, 117, 110, 116,
+            ];
+            type Params = ();
+            type Reply = u32;
+        }
+        pub struct CallsPage(());
+        impl CallsPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u64, limit: u32) -> Vec<u8> {
+                <CallsPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for CallsPage {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 36, 67, 97, 108, 108, 115, 80, 97, 103, 101,
+            ];
+            type Params = (u64, u32);
+            type Reply = super::CallsPage;
+        }
+        pub struct HistoryInfo(());
+        impl HistoryInfo {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <HistoryInfo as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for HistoryInfo {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 44, 72, 105, 115, 116, 111, 114, 121, 73, 110, 102, 111,
+            ];
+            type Params = ();
+            type Reply = super::HistoryInfo;
+        }
+        pub struct Info(());
+        impl Info {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Info as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Info {
+            const ROUTE: &'static [u8] = &[16, 80, 105, 110, 103, 16, 73, 110, 102, 111];
+            type Params = ();
+            type Reply = super::ProgramInfo;
+        }
         pub struct LastWhoCall(());
         impl LastWhoCall {
             #[allow(dead_code)]
@@ -125,15 +365,111 @@ pub mod ping {
             type Params = ();
             type Reply = (ActorId, super::PingEnum);
         }
+        pub struct Leaderboard(());
+        impl Leaderboard {
+            #[allow(dead_code)]
+            pub fn encode_call(limit: u32) -> Vec<u8> {
+                <Leaderboard as ActionIo>::encode_call(&limit)
+            }
+        }
+        impl ActionIo for Leaderboard {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 44, 76, 101, 97, 100, 101, 114, 98, 111, 97, 114, 100,
+            ];
+            type Params = u32;
+            type Reply = Vec<(ActorId, super::ActorCalls)>;
+        }
+        pub struct TrustedProxies(());
+        impl TrustedProxies {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TrustedProxies as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TrustedProxies {
+            const ROUTE: &'static [u8] = &[
+                16, 80, 105, 110, 103, 56, 84, 114, 117, 115, 116, 101, 100, 80, 114, 111, 120,
+                105, 101, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
     }
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub enum PingAdminEvent {
+    TrustedProxyAdded(ActorId),
+    TrustedProxyRemoved(ActorId),
+    Error(PingAdminError),
+    HistoryCapacitySet(u32),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PingAdminError {
+    OnlyAdminsCanChangeTrustedProxies,
+    TrustedProxyExists(ActorId),
+    TrustedProxyNotFound(ActorId),
+    OnlyAdminsCanChangeHistoryCapacity,
+    InvalidHistoryCapacity,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub enum PingEnum {
     Ping,
     Pong,
 }
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ActorCalls {
+    pub pings: u64,
+    pub pongs: u64,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallTotals {
+    pub pings: u64,
+    pub pongs: u64,
+    pub unique_callers: u64,
+    pub evicted_callers: u64,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallsPage {
+    pub calls: Vec<(ActorId, PingEnum)>,
+    pub next_offset: Option<u64>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct HistoryInfo {
+    pub capacity: u32,
+    pub len: u32,
+    pub evicted: u64,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramInfo {
+    pub version: String,
+    pub git_rev: String,
+    pub idl_hash: H256,
+    pub services: Vec<ServiceInfo>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub commands: Vec<String>,
+    pub queries: Vec<String>,
+}
 
 pub mod traits {
     use super::*;
@@ -148,9 +484,53 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait Ping {
         type Args;
+        fn add_trusted_proxy(
+            &mut self,
+            proxy: ActorId,
+        ) -> impl Call<Output = PingAdminEvent, Args = Self::Args>;
         fn ping(&mut self) -> impl Call<Output = PingEnum, Args = Self::Args>;
+        fn ping_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = PingEnum, Args = Self::Args>;
         fn pong(&mut self) -> impl Call<Output = PingEnum, Args = Self::Args>;
+        fn pong_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = PingEnum, Args = Self::Args>;
+        fn remove_trusted_proxy(
+            &mut self,
+            proxy: ActorId,
+        ) -> impl Call<Output = PingAdminEvent, Args = Self::Args>;
+        fn set_history_capacity(
+            &mut self,
+            capacity: u32,
+        ) -> impl Call<Output = PingAdminEvent, Args = Self::Args>;
+        fn actor_calls(
+            &self,
+            actor: ActorId,
+        ) -> impl Query<Output = Option<ActorCalls>, Args = Self::Args>;
         fn all_calls(&self) -> impl Query<Output = Vec<(ActorId, PingEnum)>, Args = Self::Args>;
+        fn call_totals(&self) -> impl Query<Output = CallTotals, Args = Self::Args>;
+        fn calls_by_actor(
+            &self,
+            actor: ActorId,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = CallsPage, Args = Self::Args>;
+        fn calls_count(&self) -> impl Query<Output = u32, Args = Self::Args>;
+        fn calls_page(
+            &self,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = CallsPage, Args = Self::Args>;
+        fn history_info(&self) -> impl Query<Output = HistoryInfo, Args = Self::Args>;
+        fn info(&self) -> impl Query<Output = ProgramInfo, Args = Self::Args>;
         fn last_who_call(&self) -> impl Query<Output = (ActorId, PingEnum), Args = Self::Args>;
+        fn leaderboard(
+            &self,
+            limit: u32,
+        ) -> impl Query<Output = Vec<(ActorId, ActorCalls)>, Args = Self::Args>;
+        fn trusted_proxies(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
     }
 }
@@ -53,18 +53,57 @@ impl<R> TrafficLight<R> {
 }
 impl<R: Remoting + Clone> traits::TrafficLight for TrafficLight<R> {
     type Args = R::Args;
+    fn add_trusted_proxy(
+        &mut self,
+        proxy: ActorId,
+    ) -> impl Call<Output = TrafficLightAdminEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::AddTrustedProxy>::new(self.remoting.clone(), proxy)
+    }
     fn green(&mut self) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light::io::Green>::new(self.remoting.clone(), ())
     }
+    fn green_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::GreenOnBehalfOf>::new(self.remoting.clone(), user)
+    }
     fn red(&mut self) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light::io::Red>::new(self.remoting.clone(), ())
     }
+    fn red_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::RedOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn remove_trusted_proxy(
+        &mut self,
+        proxy: ActorId,
+    ) -> impl Call<Output = TrafficLightAdminEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::RemoveTrustedProxy>::new(
+            self.remoting.clone(),
+            proxy,
+        )
+    }
     fn yellow(&mut self) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light::io::Yellow>::new(self.remoting.clone(), ())
     }
+    fn yellow_on_behalf_of(
+        &mut self,
+        user: ActorId,
+    ) -> impl Call<Output = TrafficLightEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::YellowOnBehalfOf>::new(self.remoting.clone(), user)
+    }
+    fn info(&self) -> impl Query<Output = ProgramInfo, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::Info>::new(self.remoting.clone(), ())
+    }
     fn traffic_light(&self) -> impl Query<Output = IoTrafficLightState, Args = R::Args> {
         RemotingAction::<_, traffic_light::io::TrafficLight>::new(self.remoting.clone(), ())
     }
+    fn trusted_proxies(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, traffic_light::io::TrustedProxies>::new(self.remoting.clone(), ())
+    }
 }
 
 pub mod traffic_light {
@@ -73,6 +112,21 @@ pub mod traffic_light {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AddTrustedProxy(());
+        impl AddTrustedProxy {
+            #[allow(dead_code)]
+            pub fn encode_call(proxy: ActorId) -> Vec<u8> {
+                <AddTrustedProxy as ActionIo>::encode_call(&proxy)
+            }
+        }
+        impl ActionIo for AddTrustedProxy {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 60, 65, 100, 100, 84,
+                114, 117, 115, 116, 101, 100, 80, 114, 111, 120, 121,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightAdminEvent;
+        }
         pub struct Green(());
         impl Green {
             #[allow(dead_code)]
@@ -88,6 +142,21 @@ pub mod traffic_light {
             type Params = ();
             type Reply = super::TrafficLightEvent;
         }
+        pub struct GreenOnBehalfOf(());
+        impl GreenOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <GreenOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for GreenOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 60, 71, 114, 101, 101,
+                110, 79, 110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightEvent;
+        }
         pub struct Red(());
         impl Red {
             #[allow(dead_code)]
@@ -102,6 +171,36 @@ pub mod traffic_light {
             type Params = ();
             type Reply = super::TrafficLightEvent;
         }
+        pub struct RedOnBehalfOf(());
+        impl RedOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <RedOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for RedOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 52, 82, 101, 100, 79,
+                110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightEvent;
+        }
+        pub struct RemoveTrustedProxy(());
+        impl RemoveTrustedProxy {
+            #[allow(dead_code)]
+            pub fn encode_call(proxy: ActorId) -> Vec<u8> {
+                <RemoveTrustedProxy as ActionIo>::encode_call(&proxy)
+            }
+        }
+        impl ActionIo for RemoveTrustedProxy {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 72, 82, 101, 109, 111,
+                118, 101, 84, 114, 117, 115, 116, 101, 100, 80, 114, 111, 120, 121,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightAdminEvent;
+        }
         pub struct Yellow(());
         impl Yellow {
             #[allow(dead_code)]
@@ -117,6 +216,35 @@ pub mod traffic_light {
             type Params = ();
             type Reply = super::TrafficLightEvent;
         }
+        pub struct YellowOnBehalfOf(());
+        impl YellowOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <YellowOnBehalfOf as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for YellowOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 64, 89, 101, 108, 108,
+                111, 119, 79, 110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = super::TrafficLightEvent;
+        }
+        pub struct Info(());
+        impl Info {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Info as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Info {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 16, 73, 110, 102, 111,
+            ];
+            type Params = ();
+            type Reply = super::ProgramInfo;
+        }
         pub struct TrafficLight(());
         impl TrafficLight {
             #[allow(dead_code)]
@@ -132,11 +260,42 @@ pub mod traffic_light {
             type Params = ();
             type Reply = super::IoTrafficLightState;
         }
+        pub struct TrustedProxies(());
+        impl TrustedProxies {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TrustedProxies as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TrustedProxies {
+            const ROUTE: &'static [u8] = &[
+                48, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 56, 84, 114, 117, 115,
+                116, 101, 100, 80, 114, 111, 120, 105, 101, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
     }
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightAdminEvent {
+    TrustedProxyAdded(ActorId),
+    TrustedProxyRemoved(ActorId),
+    Error(TrafficLightAdminError),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightAdminError {
+    OnlyAdminsCanChangeTrustedProxies,
+    TrustedProxyExists(ActorId),
+    TrustedProxyNotFound(ActorId),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub enum TrafficLightEvent {
     Green,
     Yellow,
@@ -145,6 +304,23 @@ pub enum TrafficLightEvent {
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramInfo {
+    pub version: String,
+    pub git_rev: String,
+    pub idl_hash: H256,
+    pub services: Vec<ServiceInfo>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub commands: Vec<String>,
+    pub queries: Vec<String>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub struct IoTrafficLightState {
     pub current_light: String,
     pub all_users: Vec<(ActorId, String)>,
@@ -163,9 +339,31 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait TrafficLight {
         type Args;
+        fn add_trusted_proxy(
+            &mut self,
+            proxy: ActorId,
+        ) -> impl Call<Output = TrafficLightAdminEvent, Args = Self::Args>;
         fn green(&mut self) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
+        fn green_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
         fn red(&mut self) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
+        fn red_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
+        fn remove_trusted_proxy(
+            &mut self,
+            proxy: ActorId,
+        ) -> impl Call<Output = TrafficLightAdminEvent, Args = Self::Args>;
         fn yellow(&mut self) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
+        fn yellow_on_behalf_of(
+            &mut self,
+            user: ActorId,
+        ) -> impl Call<Output = TrafficLightEvent, Args = Self::Args>;
+        fn info(&self) -> impl Query<Output = ProgramInfo, Args = Self::Args>;
         fn traffic_light(&self) -> impl Query<Output = IoTrafficLightState, Args = Self::Args>;
+        fn trusted_proxies(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
     }
 }
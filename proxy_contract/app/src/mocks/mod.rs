@@ -0,0 +1,7 @@
+// Mocks to test the services on the host (with "cargo test"), without
+// a node or gtest, they are only compiled with the "mocks" feature.
+
+// Module to export the mock of the remoting used by the clients
+pub mod remoting;
+// Module to export the mock of the syscalls used by the services
+pub mod syscalls;
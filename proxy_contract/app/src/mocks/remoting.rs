@@ -0,0 +1,165 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    calls::Remoting,
+    errors::{
+        Error,
+        Result,
+        RtlError
+    }
+};
+use core::future::{
+    ready,
+    Future
+};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc
+};
+
+// Message or query received by the mock, "args" are the SCALE encoded
+// arguments of the method (the payload without the route)
+#[derive(PartialEq, Clone, Debug)]
+pub struct MockCall {
+    pub target: ActorId,
+    pub service: String,
+    pub method: String,
+    pub args: Vec<u8>,
+    pub value: u128,
+    pub is_query: bool
+}
+
+impl MockCall {
+    // Method to decode the arguments of the call
+    pub fn decode_args<T: Decode>(&self) -> Option<T> {
+        T::decode(&mut self.args.as_slice()).ok()
+    }
+}
+
+// Service and method of a scripted reply
+type Route = (String, String);
+// Results of the next calls to a method, in order
+type ScriptedReplies = VecDeque<Result<Vec<u8>>>;
+
+// State of the mock, it is shared by all the clones of the remoting
+#[derive(Default)]
+struct MockRemotingState {
+    replies: Vec<(Route, ScriptedReplies)>,
+    calls: Vec<MockCall>
+}
+
+// Mock of the remoting of the clients (like GStdRemoting), the results of each
+// method are scripted in order with "reply" and "fail", and all the messages and
+// queries are recorded. A call without a scripted result fails with
+// RtlError::ReplyIsMissing.
+#[derive(Clone, Default)]
+pub struct MockRemoting {
+    state: Rc<RefCell<MockRemotingState>>
+}
+
+impl MockRemoting {
+    // Related function to create a new instance of the mock
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Method to add the reply of the next call to the method of the service
+    pub fn reply<T: Encode>(&self, service: &str, method: &str, reply: T) -> &Self {
+        self.push_result(service, method, Ok(reply.encode()))
+    }
+
+    // Method to add an error as the result of the next call to the method of
+    // the service (like a panic of the contract)
+    pub fn fail(&self, service: &str, method: &str, error: Error) -> &Self {
+        self.push_result(service, method, Err(error))
+    }
+
+    // Method to get the calls received by the mock, in order
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.borrow().calls.clone()
+    }
+
+    fn push_result(&self, service: &str, method: &str, result: Result<Vec<u8>>) -> &Self {
+        let mut state = self.state.borrow_mut();
+        let key = (service.to_string(), method.to_string());
+
+        match state.replies.iter_mut().find(|(method_key, _)| *method_key == key) {
+            Some((_, results)) => results.push_back(result),
+            None => state.replies.push((key, VecDeque::from([result])))
+        }
+
+        drop(state);
+
+        self
+    }
+
+    // Helper method to record a call and get its scripted result, the reply
+    // starts with the route of the method (as the replies of the programs)
+    fn handle(&self, target: ActorId, payload: &[u8], value: u128, is_query: bool) -> Result<Vec<u8>> {
+        let mut args = payload;
+        let service = String::decode(&mut args)?;
+        let method = String::decode(&mut args)?;
+        let route = &payload[..payload.len() - args.len()];
+
+        let mut state = self.state.borrow_mut();
+
+        state.calls.push(MockCall {
+            target,
+            service: service.clone(),
+            method: method.clone(),
+            args: args.to_vec(),
+            value,
+            is_query
+        });
+
+        let reply = state.replies
+            .iter_mut()
+            .find(|((reply_service, reply_method), _)| *reply_service == service && *reply_method == method)
+            .and_then(|(_, results)| results.pop_front())
+            .unwrap_or(Err(RtlError::ReplyIsMissing.into()))?;
+
+        Ok([route, reply.as_slice()].concat())
+    }
+}
+
+impl Remoting for MockRemoting {
+    type Args = ();
+
+    // The mock can't create programs
+    async fn activate(
+        self,
+        _code_id: CodeId,
+        _salt: impl AsRef<[u8]>,
+        _payload: impl AsRef<[u8]>,
+        _gas_limit: Option<u64>,
+        _value: u128,
+        _args: ()
+    ) -> Result<impl Future<Output = Result<(ActorId, Vec<u8>)>>> {
+        Ok(ready(Err::<(ActorId, Vec<u8>), Error>(
+            RtlError::ProgramCodeIsNotFound.into()
+        )))
+    }
+
+    async fn message(
+        self,
+        target: ActorId,
+        payload: impl AsRef<[u8]>,
+        _gas_limit: Option<u64>,
+        value: u128,
+        _args: ()
+    ) -> Result<impl Future<Output = Result<Vec<u8>>>> {
+        Ok(ready(self.handle(target, payload.as_ref(), value, false)))
+    }
+
+    async fn query(
+        self,
+        target: ActorId,
+        payload: impl AsRef<[u8]>,
+        _gas_limit: Option<u64>,
+        value: u128,
+        _args: ()
+    ) -> Result<Vec<u8>> {
+        self.handle(target, payload.as_ref(), value, true)
+    }
+}
@@ -0,0 +1,111 @@
+// Necesary crates
+use sails_rs::prelude::*;
+use std::{
+    cell::RefCell,
+    thread_local
+};
+
+// Mock of the message and block data that the services read from the
+// runtime, each test thread has its own data
+#[derive(Default, Clone)]
+struct MockSyscalls {
+    source: ActorId,
+    value: u128,
+    block_height: u32,
+    program_id: ActorId,
+    value_available: u128,
+    sends_fail: bool,
+    sent_values: Vec<(ActorId, u128)>
+}
+
+// Error returned by "msg::send_bytes" when the sends are set to fail
+#[derive(Debug)]
+pub struct SendError;
+
+thread_local! {
+    static SYSCALLS: RefCell<MockSyscalls> = RefCell::new(MockSyscalls::default());
+}
+
+// Sets the sender of the current message
+pub fn set_source(source: ActorId) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.source = source);
+}
+
+// Sets the value attached to the current message
+pub fn set_value(value: u128) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.value = value);
+}
+
+// Sets the current block
+pub fn set_block_height(block_height: u32) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.block_height = block_height);
+}
+
+// Sets the id of the program
+pub fn set_program_id(program_id: ActorId) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.program_id = program_id);
+}
+
+// Sets the balance of the program
+pub fn set_value_available(value_available: u128) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.value_available = value_available);
+}
+
+// Sets if the messages sent by the program fail (like a refund to a program
+// that can't receive value)
+pub fn set_sends_fail(sends_fail: bool) {
+    SYSCALLS.with_borrow_mut(|syscalls| syscalls.sends_fail = sends_fail);
+}
+
+// Returns the values sent by the program (like refunds), with their receiver
+pub fn sent_values() -> Vec<(ActorId, u128)> {
+    SYSCALLS.with_borrow(|syscalls| syscalls.sent_values.clone())
+}
+
+// Clears the data of the current thread
+pub fn reset() {
+    SYSCALLS.with_borrow_mut(|syscalls| *syscalls = MockSyscalls::default());
+}
+
+// Same functions as "gstd::msg" that are used by the services
+pub mod msg {
+    use super::*;
+
+    pub fn source() -> ActorId {
+        SYSCALLS.with_borrow(|syscalls| syscalls.source)
+    }
+
+    pub fn value() -> u128 {
+        SYSCALLS.with_borrow(|syscalls| syscalls.value)
+    }
+
+    // The value is stored to be checked with "sent_values", the payload is ignored
+    pub fn send_bytes(program: ActorId, _payload: impl AsRef<[u8]>, value: u128) -> Result<MessageId, SendError> {
+        SYSCALLS.with_borrow_mut(|syscalls| {
+            if syscalls.sends_fail {
+                return Err(SendError);
+            }
+
+            syscalls.sent_values.push((program, value));
+
+            Ok(MessageId::default())
+        })
+    }
+}
+
+// Same functions as "gstd::exec" that are used by the services
+pub mod exec {
+    use super::*;
+
+    pub fn block_height() -> u32 {
+        SYSCALLS.with_borrow(|syscalls| syscalls.block_height)
+    }
+
+    pub fn program_id() -> ActorId {
+        SYSCALLS.with_borrow(|syscalls| syscalls.program_id)
+    }
+
+    pub fn value_available() -> u128 {
+        SYSCALLS.with_borrow(|syscalls| syscalls.value_available)
+    }
+}
@@ -1,3 +1,8 @@
 pub mod proxy_service;
-pub mod proxy_ping_caller_service;
-pub mod proxy_taffic_light_caller_service;
\ No newline at end of file
+pub mod proxy_credits_service;
+pub mod proxy_relayer_service;
+pub mod proxy_sessions_service;
+
+// The caller services of the enabled targets are generated by the build
+// script (app/build.rs) from the IDL of each target contract
+include!(concat!(env!("OUT_DIR"), "/caller_services.rs"));
@@ -0,0 +1,96 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefMut
+};
+// Import the syscalls (they are mocked in the tests)
+use crate::syscalls::msg;
+// Import the state
+use crate::states::proxy_state::ProxyState;
+
+// Proxy credits service, users can deposit value to pay the fees of the
+// proxied commands without attaching value in each call
+pub struct ProxyCreditsService<'a> {
+    pub state: RefMut<'a, ProxyState>
+}
+
+#[service]
+impl<'a> ProxyCreditsService<'a> {
+    pub const fn new(
+        state: RefMut<'a, ProxyState>
+    ) -> Self {
+        Self {
+            state
+        }
+    }
+
+    // Method (command) to add the attached value to the credit of the caller
+    pub fn deposit(&mut self) -> ProxyCreditsEvent {
+        let caller = msg::source();
+        let amount = msg::value();
+
+        if amount == 0 {
+            return ProxyCreditsEvent::Error(
+                ProxyCreditsErrors::NoValueAttached
+            );
+        }
+
+        let balance = self.state.credits.deposit(caller, amount);
+        self.state.lock_import();
+
+        ProxyCreditsEvent::Deposited(balance)
+    }
+
+    // Method (command) to send back to the caller an amount of its credit
+    pub fn withdraw(&mut self, amount: u128) -> ProxyCreditsEvent {
+        let caller = msg::source();
+        let balance = self.state.credits.balance(caller);
+
+        if amount == 0 || amount > balance {
+            return ProxyCreditsEvent::Error(
+                ProxyCreditsErrors::InsufficientCredit(balance)
+            );
+        }
+
+        if msg::send_bytes(caller, [], amount).is_err() {
+            return ProxyCreditsEvent::Error(
+                ProxyCreditsErrors::TransferFailed
+            );
+        }
+
+        self.state.lock_import();
+
+        match self.state.credits.withdraw(caller, amount) {
+            Ok(balance) => ProxyCreditsEvent::Withdrawn(balance),
+            Err(balance) => ProxyCreditsEvent::Error(
+                ProxyCreditsErrors::InsufficientCredit(balance)
+            )
+        }
+    }
+
+    // Method (query) that returns the credit of an account
+    pub fn balance(&self, account: ActorId) -> ProxyCreditsEvent {
+        ProxyCreditsEvent::Balance(self.state.credits.balance(account))
+    }
+}
+
+// Enum to set the events from the proxy credits service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyCreditsEvent {
+    Deposited(u128),
+    Withdrawn(u128),
+    Balance(u128),
+    Error(ProxyCreditsErrors)
+}
+
+// Enum to set the errors from the proxy credits service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyCreditsErrors {
+    NoValueAttached,
+    InsufficientCredit(u128),
+    TransferFailed
+}
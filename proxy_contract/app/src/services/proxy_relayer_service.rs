@@ -0,0 +1,203 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefCell
+};
+// Import the syscalls (they are mocked in the tests)
+use crate::syscalls::exec;
+// Import the state and the proxy routes
+use crate::states::{
+    proxy_state::ProxyState,
+    proxy_route::{
+        ProxyRoute,
+        ProxyTarget
+    }
+};
+// Import the clients of the target contracts
+use crate::clients::ProxyClients;
+// Import the caller services of the enabled targets, they execute the operations
+#[cfg(feature = "ping-caller")]
+use super::proxy_ping_caller_service::{
+    ProxyPingCallerService,
+    ProxyPingCallerEvent
+};
+#[cfg(feature = "traffic-light-caller")]
+use super::proxy_taffic_light_caller_service::{
+    ProxyTrafficLightCallerService,
+    ProxyTrafficLightCallerEvent
+};
+
+// Context used by the substrate wallets to sign messages with sr25519
+const SR25519_SIGNING_CONTEXT: &[u8] = b"substrate";
+
+// Proxy relayer service, a relayer can send proxied commands on behalf of users
+// that signed the operation (meta transactions), the relayer pays the gas fees.
+// It receives the RefCells from the program because it has to create the caller
+// services that will execute the operation.
+pub struct ProxyRelayerService<'a> {
+    proxy_state: &'a RefCell<ProxyState>,
+    clients: &'a ProxyClients
+}
+
+#[service]
+impl<'a> ProxyRelayerService<'a> {
+    // Related function to create a new instance of the service
+    pub const fn new(
+        proxy_state: &'a RefCell<ProxyState>,
+        clients: &'a ProxyClients
+    ) -> Self {
+        Self {
+            proxy_state,
+            clients
+        }
+    }
+
+    // Method (command) that executes a proxied operation signed by a user:
+    // - public_key: public key of the user (it is used as the user address).
+    // - operation: SCALE encoded ProxyRoute to execute.
+    // - nonce: next nonce of the user (see the "Nonce" query).
+    // - deadline: last block where the operation can be executed.
+    // - signature: sr25519 or ed25519 signature of the SCALE encoded tuple
+    //   (proxy id, public_key, operation, nonce, deadline).
+    // The operation is executed as the user for all authorization purposes, the
+    // nonce already protects it from replays, so no idempotency key is used.
+    pub async fn relay_call(
+        &mut self,
+        public_key: [u8; 32],
+        operation: Vec<u8>,
+        nonce: u64,
+        deadline: u32,
+        signature: RelaySignature
+    ) -> ProxyRelayerEvent {
+        if exec::block_height() > deadline {
+            return ProxyRelayerEvent::Error(
+                ProxyRelayerErrors::DeadlineExpired(deadline)
+            );
+        }
+
+        let Ok(route) = ProxyRoute::decode(&mut operation.as_slice()) else {
+            return ProxyRelayerEvent::Error(
+                ProxyRelayerErrors::InvalidOperation
+            );
+        };
+
+        let message = (exec::program_id(), public_key, operation, nonce, deadline).encode();
+
+        if !verify_signature(&public_key, &message, &signature) {
+            return ProxyRelayerEvent::Error(
+                ProxyRelayerErrors::InvalidSignature
+            );
+        }
+
+        let user = ActorId::from(public_key);
+
+        // The nonce is used only if the signature is valid
+        if let Err(expected_nonce) = self.proxy_state.borrow_mut().relay.use_nonce(user, nonce) {
+            return ProxyRelayerEvent::Error(
+                ProxyRelayerErrors::InvalidNonce(expected_nonce)
+            );
+        }
+
+        self.proxy_state.borrow_mut().lock_import();
+
+        // Each arm creates the caller service of the target, only the targets
+        // that are enabled in the build are compiled. The service borrows the state
+        // only to read and change it, never while the reply of the target is awaited
+        let event = match route.target() {
+            #[cfg(feature = "ping-caller")]
+            ProxyTarget::PingContract => ProxyPingCallerService::new(
+                    self.proxy_state,
+                    self.clients.ping_client.borrow_mut()
+                )
+                .call_route_as(route, user, None)
+                .await
+                .map(ProxyRelayerEvent::PingCaller),
+            #[cfg(feature = "traffic-light-caller")]
+            ProxyTarget::TrafficLightContract => ProxyTrafficLightCallerService::new(
+                    self.proxy_state,
+                    self.clients.traffic_light_client.borrow_mut()
+                )
+                .call_route_as(route, user, None)
+                .await
+                .map(ProxyRelayerEvent::TrafficLightCaller),
+            // The routes of the targets that are not enabled in the build
+            #[allow(unreachable_patterns)]
+            _ => None
+        };
+
+        event.unwrap_or(ProxyRelayerEvent::Error(
+            ProxyRelayerErrors::InvalidOperation
+        ))
+    }
+
+    // Method (query) that returns the nonce that the next operation of the user must use
+    pub fn nonce(&self, user: ActorId) -> ProxyRelayerEvent {
+        ProxyRelayerEvent::Nonce(self.proxy_state.borrow().relay.nonce(user))
+    }
+}
+
+// Helper function to verify the signature of a message with the public key of the user
+fn verify_signature(public_key: &[u8; 32], message: &[u8], signature: &RelaySignature) -> bool {
+    match signature {
+        RelaySignature::Sr25519(signature) => {
+            let (Ok(public_key), Ok(signature)) = (
+                schnorrkel::PublicKey::from_bytes(public_key),
+                schnorrkel::Signature::from_bytes(signature)
+            ) else {
+                return false;
+            };
+
+            public_key
+                .verify_simple(SR25519_SIGNING_CONTEXT, message, &signature)
+                .is_ok()
+        },
+        RelaySignature::Ed25519(signature) => {
+            let Ok(public_key) = ed25519_dalek::VerifyingKey::from_bytes(public_key) else {
+                return false;
+            };
+            let signature = ed25519_dalek::Signature::from_bytes(signature);
+
+            public_key
+                .verify_strict(message, &signature)
+                .is_ok()
+        }
+    }
+}
+
+// Enum to set the signature of the operation
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RelaySignature {
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64])
+}
+
+// Enum to set the events from the proxy relayer service, the indexes are set
+// because the variants of the callers depend on the enabled features
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRelayerEvent {
+    #[cfg(feature = "ping-caller")]
+    #[codec(index = 0)]
+    PingCaller(ProxyPingCallerEvent),
+    #[cfg(feature = "traffic-light-caller")]
+    #[codec(index = 1)]
+    TrafficLightCaller(ProxyTrafficLightCallerEvent),
+    #[codec(index = 2)]
+    Nonce(u64),
+    #[codec(index = 3)]
+    Error(ProxyRelayerErrors)
+}
+
+// Enum to set the errors from the proxy relayer service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRelayerErrors {
+    DeadlineExpired(u32),
+    InvalidOperation,
+    InvalidSignature,
+    InvalidNonce(u64)
+}
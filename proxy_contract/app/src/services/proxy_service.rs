@@ -1,11 +1,33 @@
 // Necesary crates
 use sails_rs::{
     prelude::*,
-    cell::RefMut,
-    gstd::msg
+    cell::RefMut
+};
+// Import the syscalls (they are mocked in the tests)
+use crate::syscalls::{
+    exec,
+    msg
 };
 // Import the state
-use crate::states::proxy_state::ProxyState;
+use crate::states::{
+    proxy_state::ProxyState,
+    proxy_route::{
+        ProxyRoute,
+        ProxyTarget
+    },
+    metrics_state::ProxyMetrics,
+    rate_limit_state::{
+        RateLimit,
+        RateLimitsConfig
+    },
+    fees_state::FeesInfo,
+    snapshot_state::{
+        ProxyStateExport,
+        SnapshotError
+    }
+};
+// Import the info of the program
+use crate::program_info::ProgramInfo;
 
 pub struct ProxyService<'a> {
     pub state: RefMut<'a, ProxyState>
@@ -21,7 +43,9 @@ impl<'a> ProxyService<'a> {
         }
     }
 
-    pub fn change_ping_contract_id(&mut self, contract_id: ActorId) -> ProxyEvent {
+    // Method (command) to set the id of a target contract, only the targets
+    // that are enabled in the build can be set
+    pub fn change_contract_id(&mut self, target: ProxyTarget, contract_id: ActorId) -> ProxyEvent {
         let caller = msg::source();
         if !self.state.is_admin(caller) {
             return ProxyEvent::Error(
@@ -29,22 +53,64 @@ impl<'a> ProxyService<'a> {
             );
         }
 
-        self.state.ping_contract_id = Some(contract_id);
+        if !target.is_enabled() {
+            return ProxyEvent::Error(
+                ProxyErrors::TargetNotEnabled(target)
+            );
+        }
+
+        self.state.set_contract_id(target, contract_id);
+        self.state.lock_import();
 
-        ProxyEvent::PingContractIdSet
+        ProxyEvent::ContractIdSet(target)
     }
 
+    // Method (command) of the first release to set the id of the ping contract,
+    // it is the same as "change_contract_id" with the ping contract target
+    pub fn change_ping_contract_id(&mut self, contract_id: ActorId) -> ProxyEvent {
+        match self.change_contract_id(ProxyTarget::PingContract, contract_id) {
+            ProxyEvent::ContractIdSet(_) => ProxyEvent::PingContractIdSet,
+            event => event
+        }
+    }
+
+    // Method (command) of the first release to set the id of the traffic light
+    // contract, it is the same as "change_contract_id" with the traffic light target
     pub fn change_traffic_light_contract_id(&mut self, contract_id: ActorId) -> ProxyEvent {
+        match self.change_contract_id(ProxyTarget::TrafficLightContract, contract_id) {
+            ProxyEvent::ContractIdSet(_) => ProxyEvent::TrafficLightContractIdSet,
+            event => event
+        }
+    }
+
+    // Method (command) to set if the commands are sent to the target on behalf of
+    // the users, it has to be enabled only after the proxy was added as a trusted
+    // proxy in the target (AddTrustedProxy), if not, the target rejects them.
+    // While it is disabled, the plain commands are sent (the proxy is the caller)
+    pub fn set_on_behalf_of(&mut self, target: ProxyTarget, enabled: bool) -> ProxyEvent {
         let caller = msg::source();
         if !self.state.is_admin(caller) {
             return ProxyEvent::Error(
-                ProxyErrors::OnlyAdminsCanChangeContactId
+                ProxyErrors::OnlyAdminsCanChangeOnBehalfOf
             );
         }
 
-        self.state.traffic_light_contract_id = Some(contract_id);
+        if !target.is_enabled() {
+            return ProxyEvent::Error(
+                ProxyErrors::TargetNotEnabled(target)
+            );
+        }
 
-        ProxyEvent::PingContractIdSet
+        self.state.set_on_behalf_of(target, enabled);
+        self.state.lock_import();
+
+        ProxyEvent::OnBehalfOfSet(target, enabled)
+    }
+
+    // Method (query) that returns the targets that receive the commands on
+    // behalf of the users
+    pub fn on_behalf_of(&self) -> ProxyEvent {
+        ProxyEvent::OnBehalfOf(self.state.on_behalf_of.clone())
     }
 
     pub fn add_admin(&mut self, new_admin: ActorId) -> ProxyEvent {
@@ -62,24 +128,261 @@ impl<'a> ProxyService<'a> {
         }
 
         self.state.admins.push(new_admin);
+        self.state.lock_import();
 
         ProxyEvent::AdminAdded(new_admin)
     }
 
+    pub fn reset_metrics(&mut self) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanResetMetrics
+            );
+        }
+
+        self.state.metrics.reset();
+        self.state.lock_import();
+
+        ProxyEvent::MetricsReset
+    }
+
+    pub fn set_rate_limit(
+        &mut self,
+        route: ProxyRoute,
+        max_calls: u32,
+        window_blocks: u32
+    ) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanChangeRateLimits
+            );
+        }
+
+        if max_calls == 0 || window_blocks == 0 {
+            return ProxyEvent::Error(
+                ProxyErrors::InvalidRateLimit
+            );
+        }
+
+        self.state.rate_limits.set_limit(route, RateLimit {
+            max_calls,
+            window_blocks
+        });
+        self.state.lock_import();
+
+        ProxyEvent::RateLimitSet(route)
+    }
+
+    pub fn remove_rate_limit(&mut self, route: ProxyRoute) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanChangeRateLimits
+            );
+        }
+
+        if !self.state.rate_limits.remove_limit(route) {
+            return ProxyEvent::Error(
+                ProxyErrors::RateLimitNotSet(route)
+            );
+        }
+        self.state.lock_import();
+
+        ProxyEvent::RateLimitRemoved(route)
+    }
+
+    pub fn add_rate_limit_exempt(&mut self, address: ActorId) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanChangeRateLimits
+            );
+        }
+
+        if self.state.rate_limits.is_exempt(address) {
+            return ProxyEvent::Error(
+                ProxyErrors::AddressIsAlreadyExempt(address)
+            );
+        }
+
+        self.state.rate_limits.exempt.push(address);
+        self.state.lock_import();
+
+        ProxyEvent::RateLimitExemptAdded(address)
+    }
+
+    pub fn remove_rate_limit_exempt(&mut self, address: ActorId) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanChangeRateLimits
+            );
+        }
+
+        if !self.state.rate_limits.is_exempt(address) {
+            return ProxyEvent::Error(
+                ProxyErrors::AddressIsNotExempt(address)
+            );
+        }
+
+        self.state.rate_limits.exempt.retain(|exempt| *exempt != address);
+        self.state.lock_import();
+
+        ProxyEvent::RateLimitExemptRemoved(address)
+    }
+
+    pub fn set_route_fee(&mut self, route: ProxyRoute, fee: u128) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanManageFees
+            );
+        }
+
+        self.state.fees.set_fee(route, fee);
+        self.state.lock_import();
+
+        ProxyEvent::RouteFeeSet(route, fee)
+    }
+
+    pub fn withdraw_fees(&mut self, to: ActorId, amount: u128) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanManageFees
+            );
+        }
+
+        let available = self.state.fees.available();
+        if amount == 0 || amount > available {
+            return ProxyEvent::Error(
+                ProxyErrors::InsufficientFeesToWithdraw(available)
+            );
+        }
+
+        if msg::send_bytes(to, [], amount).is_err() {
+            return ProxyEvent::Error(
+                ProxyErrors::FeesTransferFailed
+            );
+        }
+
+        self.state.fees.total_withdrawn += amount;
+        self.state.lock_import();
+
+        ProxyEvent::FeesWithdrawn(to, amount)
+    }
+
+    pub fn fees(&self) -> ProxyEvent {
+        ProxyEvent::Fees(self.state.fees.info(exec::value_available()))
+    }
+
+    pub fn rate_limits(&self) -> ProxyEvent {
+        ProxyEvent::RateLimits(self.state.rate_limits.config())
+    }
+
+    pub fn set_idempotency_ttl(&mut self, ttl_blocks: u32) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanChangeIdempotencyTtl
+            );
+        }
+
+        self.state.idempotency.ttl_blocks = ttl_blocks;
+        self.state.lock_import();
+
+        ProxyEvent::IdempotencyTtlSet(ttl_blocks)
+    }
+
+    pub fn idempotency_ttl(&self) -> ProxyEvent {
+        ProxyEvent::IdempotencyTtl(self.state.idempotency.ttl_blocks)
+    }
+
+    // Method (command) to restore the configuration exported from another proxy,
+    // it can only be used once, right after the contract is created (before any
+    // command that changes the state). The caller is kept as admin after the import.
+    pub fn import_state(&mut self, export: ProxyStateExport) -> ProxyEvent {
+        let caller = msg::source();
+        if !self.state.is_admin(caller) {
+            return ProxyEvent::Error(
+                ProxyErrors::OnlyAdminsCanImportState
+            );
+        }
+
+        if self.state.import_locked {
+            return ProxyEvent::Error(
+                ProxyErrors::StateImportLocked
+            );
+        }
+
+        let mut snapshot = match export.snapshot() {
+            Ok(snapshot) => snapshot,
+            Err(SnapshotError::IncompatibleSchemaVersion(version)) => {
+                return ProxyEvent::Error(
+                    ProxyErrors::IncompatibleSchemaVersion(version)
+                );
+            },
+            Err(SnapshotError::InvalidChecksum) => {
+                return ProxyEvent::Error(
+                    ProxyErrors::InvalidStateChecksum
+                );
+            },
+            Err(SnapshotError::InvalidData) => {
+                return ProxyEvent::Error(
+                    ProxyErrors::InvalidStateData
+                );
+            }
+        };
+
+        if !snapshot.admins.contains(&caller) {
+            snapshot.admins.push(caller);
+        }
+
+        self.state.restore(snapshot);
+
+        ProxyEvent::StateImported
+    }
+
+    // Method (query) that returns the versioned snapshot of the proxy configuration
+    pub fn export_state(&self) -> ProxyEvent {
+        ProxyEvent::StateExport(
+            ProxyStateExport::new(&self.state.snapshot())
+        )
+    }
+
+    // Method (query) that returns the version, git revision, IDL hash and services
+    // of the deployed build, so monitoring can detect version drift
+    pub fn info(&self) -> ProxyEvent {
+        ProxyEvent::Info(ProgramInfo::current())
+    }
+
+    pub fn metrics(&self) -> ProxyEvent {
+        ProxyEvent::Metrics(self.state.metrics.clone())
+    }
+
+    // Method (query) of the first release that returns the ids of the ping and
+    // traffic light contracts
     pub fn contracts_id(&self) -> ProxyEvent {
         ProxyEvent::ContractsId(ContractsId {
-            ping_contract_id: self.state.ping_contract_id,
-            traffic_light_contract_id: self.state.traffic_light_contract_id
+            ping_contract_id: self.state.contract_id(ProxyTarget::PingContract),
+            traffic_light_contract_id: self.state.contract_id(ProxyTarget::TrafficLightContract)
         })
     }
+
+    // Method (query) that returns the ids of the target contracts that are set
+    pub fn contract_ids(&self) -> ProxyEvent {
+        ProxyEvent::ContractIds(self.state.contract_ids.clone())
+    }
 }
 
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct ContractsId {
-    ping_contract_id: Option<ActorId>,
-    traffic_light_contract_id: Option<ActorId>
+    pub ping_contract_id: Option<ActorId>,
+    pub traffic_light_contract_id: Option<ActorId>
 }
 
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
@@ -92,7 +395,26 @@ pub enum ProxyEvent {
     PingContractId(Option<ActorId>),
     TrafficLightContractId(Option<ActorId>),
     ContractsId(ContractsId),
-    Error(ProxyErrors)
+    Error(ProxyErrors),
+    ContractIdSet(ProxyTarget),
+    ContractIds(Vec<(ProxyTarget, ActorId)>),
+    Metrics(ProxyMetrics),
+    MetricsReset,
+    RateLimitSet(ProxyRoute),
+    RateLimitRemoved(ProxyRoute),
+    RateLimitExemptAdded(ActorId),
+    RateLimitExemptRemoved(ActorId),
+    RateLimits(RateLimitsConfig),
+    RouteFeeSet(ProxyRoute, u128),
+    FeesWithdrawn(ActorId, u128),
+    Fees(FeesInfo),
+    IdempotencyTtlSet(u32),
+    IdempotencyTtl(u32),
+    StateImported,
+    StateExport(ProxyStateExport),
+    Info(ProgramInfo),
+    OnBehalfOfSet(ProxyTarget, bool),
+    OnBehalfOf(Vec<ProxyTarget>)
 }
 
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
@@ -100,5 +422,22 @@ pub enum ProxyEvent {
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum ProxyErrors {
     OnlyAdminsCanChangeContactId,
-    AdminExistsInContract(ActorId)
+    AdminExistsInContract(ActorId),
+    OnlyAdminsCanResetMetrics,
+    OnlyAdminsCanChangeRateLimits,
+    InvalidRateLimit,
+    RateLimitNotSet(ProxyRoute),
+    AddressIsAlreadyExempt(ActorId),
+    AddressIsNotExempt(ActorId),
+    OnlyAdminsCanManageFees,
+    InsufficientFeesToWithdraw(u128),
+    FeesTransferFailed,
+    OnlyAdminsCanChangeIdempotencyTtl,
+    OnlyAdminsCanImportState,
+    StateImportLocked,
+    IncompatibleSchemaVersion(u32),
+    InvalidStateChecksum,
+    InvalidStateData,
+    OnlyAdminsCanChangeOnBehalfOf,
+    TargetNotEnabled(ProxyTarget)
 }
\ No newline at end of file
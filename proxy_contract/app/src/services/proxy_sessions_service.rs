@@ -0,0 +1,185 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    cell::RefMut
+};
+// Import the syscalls (they are mocked in the tests)
+use crate::syscalls::{
+    exec,
+    msg
+};
+// Import the state
+use crate::states::{
+    proxy_state::ProxyState,
+    proxy_route::ProxyRoute,
+    session_state::SessionKey
+};
+
+// Proxy sessions service, users can register session keys that send proxied
+// commands as if they were the user, only for the routes in the scope of the key.
+// The registration is a proposal, the account of the session key has to accept
+// it before the key is used (so no one can take an account as its session key)
+pub struct ProxySessionsService<'a> {
+    pub state: RefMut<'a, ProxyState>
+}
+
+#[service]
+impl<'a> ProxySessionsService<'a> {
+    pub const fn new(
+        state: RefMut<'a, ProxyState>
+    ) -> Self {
+        Self {
+            state
+        }
+    }
+
+    // Method (command) to propose (or replace) a session key of the caller, the
+    // session key has to accept it with "accept_session_key"
+    // - scope: routes that the session key can call.
+    // - expires_at: last block where the session key can be used.
+    // - max_calls: max number of commands that the session key can send.
+    pub fn register_session_key(
+        &mut self,
+        session_key: ActorId,
+        scope: Vec<ProxyRoute>,
+        expires_at: u32,
+        max_calls: u32
+    ) -> ProxySessionsEvent {
+        let caller = msg::source();
+
+        // Session keys can't register other session keys
+        if self.state.sessions.is_session_key(caller) || session_key == caller {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::InvalidSessionKey(session_key)
+            );
+        }
+
+        if let Some(session) = self.state.sessions.sessions.get(&session_key) {
+            if session.owner != caller {
+                return ProxySessionsEvent::Error(
+                    ProxySessionsErrors::SessionKeyExists(session_key)
+                );
+            }
+        }
+
+        if scope.is_empty() || max_calls == 0 || expires_at <= exec::block_height() {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::InvalidSessionParameters
+            );
+        }
+
+        let mut scope = scope;
+        scope.sort_by_key(|route| *route as u8);
+        scope.dedup();
+
+        self.state.sessions.sessions.insert(session_key, SessionKey {
+            owner: caller,
+            scope,
+            expires_at,
+            max_calls,
+            calls: 0,
+            accepted: false
+        });
+        self.state.lock_import();
+
+        ProxySessionsEvent::SessionKeyRegistered(session_key)
+    }
+
+    // Method (command) that the session key (the caller) sends to accept the key
+    // proposed by the owner, after it, the commands of the caller in the scope of
+    // the key are sent as the owner
+    pub fn accept_session_key(&mut self, owner: ActorId) -> ProxySessionsEvent {
+        let caller = msg::source();
+
+        // Owners of session keys can't be session keys
+        if !self.state.sessions.owner_session_keys(caller).is_empty() {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::InvalidSessionKey(caller)
+            );
+        }
+
+        let Some(session) = self.state.sessions.sessions
+            .get_mut(&caller)
+            .filter(|session| session.owner == owner)
+        else {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::SessionKeyNotProposed(owner)
+            );
+        };
+
+        if session.accepted {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::SessionKeyAlreadyAccepted(caller)
+            );
+        }
+
+        session.accepted = true;
+        self.state.lock_import();
+
+        ProxySessionsEvent::SessionKeyAccepted(caller)
+    }
+
+    // Method (command) to revoke a session key, the owner or the session key
+    // itself can revoke it (also if it was not accepted)
+    pub fn revoke_session_key(&mut self, session_key: ActorId) -> ProxySessionsEvent {
+        let caller = msg::source();
+
+        let Some(session) = self.state.sessions.sessions.get(&session_key) else {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::SessionKeyNotFound(session_key)
+            );
+        };
+
+        if session.owner != caller && session_key != caller {
+            return ProxySessionsEvent::Error(
+                ProxySessionsErrors::OnlyOwnerOrSessionKeyCanRevoke
+            );
+        }
+
+        self.state.sessions.sessions.remove(&session_key);
+        self.state.lock_import();
+
+        ProxySessionsEvent::SessionKeyRevoked(session_key)
+    }
+
+    // Method (query) that returns the data of a session key
+    pub fn session_key(&self, session_key: ActorId) -> ProxySessionsEvent {
+        ProxySessionsEvent::SessionKey(
+            self.state.sessions.sessions.get(&session_key).cloned()
+        )
+    }
+
+    // Method (query) that returns the session keys registered by an owner
+    pub fn session_keys(&self, owner: ActorId) -> ProxySessionsEvent {
+        ProxySessionsEvent::SessionKeys(
+            self.state.sessions.owner_session_keys(owner)
+        )
+    }
+}
+
+// Enum to set the events from the proxy sessions service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxySessionsEvent {
+    SessionKeyRegistered(ActorId),
+    SessionKeyRevoked(ActorId),
+    SessionKey(Option<SessionKey>),
+    SessionKeys(Vec<(ActorId, SessionKey)>),
+    Error(ProxySessionsErrors),
+    SessionKeyAccepted(ActorId)
+}
+
+// Enum to set the errors from the proxy sessions service
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxySessionsErrors {
+    InvalidSessionKey(ActorId),
+    SessionKeyExists(ActorId),
+    InvalidSessionParameters,
+    SessionKeyNotFound(ActorId),
+    OnlyOwnerOrSessionKeyCanRevoke,
+    SessionKeyNotProposed(ActorId),
+    SessionKeyAlreadyAccepted(ActorId)
+}
@@ -0,0 +1,60 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    collections::HashMap
+};
+
+// State to store the prepaid credit of each user, the credit is used
+// to pay the fees of the proxied commands without attaching value
+#[derive(Default)]
+pub struct CreditsState {
+    pub balances: HashMap<ActorId, u128>
+}
+
+impl CreditsState {
+    // Returns the credit of the user
+    pub fn balance(&self, account: ActorId) -> u128 {
+        self.balances
+            .get(&account)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // Method to add credit to the user, returns the new balance
+    pub fn deposit(&mut self, account: ActorId, amount: u128) -> u128 {
+        let balance = self.balances
+            .entry(account)
+            .or_default();
+
+        *balance = balance.saturating_add(amount);
+
+        *balance
+    }
+
+    // Method to remove credit from the user, returns the new balance
+    // or the current balance if the user does not have enough credit
+    pub fn withdraw(&mut self, account: ActorId, amount: u128) -> Result<u128, u128> {
+        let balance = self.balance(account);
+
+        if amount > balance {
+            return Err(balance);
+        }
+
+        let new_balance = balance - amount;
+
+        if new_balance == 0 {
+            self.balances.remove(&account);
+        } else {
+            self.balances.insert(account, new_balance);
+        }
+
+        Ok(new_balance)
+    }
+
+    // Method to pay a fee with the credit of the user, returns the new balance
+    // or the current balance if the user does not have enough credit (in that
+    // case nothing is charged)
+    pub fn charge(&mut self, account: ActorId, amount: u128) -> Result<u128, u128> {
+        self.withdraw(account, amount)
+    }
+}
@@ -0,0 +1,63 @@
+// Necesary crates
+use sails_rs::prelude::*;
+// Import the proxy routes
+use super::proxy_route::ProxyRoute;
+
+// Struct to send the fees information to the user
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeesInfo {
+    pub route_fees: Vec<(ProxyRoute, u128)>,
+    pub total_collected: u128,
+    pub total_withdrawn: u128,
+    pub balance: u128
+}
+
+// State to store the fee of each route and the fees collected by the proxy
+#[derive(Default)]
+pub struct FeesState {
+    pub route_fees: Vec<(ProxyRoute, u128)>,
+    pub total_collected: u128,
+    pub total_withdrawn: u128
+}
+
+impl FeesState {
+    // Returns the fee of the route (zero if it was not set)
+    pub fn fee(&self, route: ProxyRoute) -> u128 {
+        self.route_fees
+            .iter()
+            .find(|(key, _)| *key == route)
+            .map(|(_, fee)| *fee)
+            .unwrap_or_default()
+    }
+
+    // Method to set the fee of a route, a zero fee removes it
+    pub fn set_fee(&mut self, route: ProxyRoute, fee: u128) {
+        self.route_fees.retain(|(key, _)| *key != route);
+
+        if fee > 0 {
+            self.route_fees.push((route, fee));
+        }
+    }
+
+    // Method to store a fee charged by the proxy
+    pub fn collect(&mut self, fee: u128) {
+        self.total_collected = self.total_collected.saturating_add(fee);
+    }
+
+    // Returns the fees that can still be withdrawn
+    pub fn available(&self) -> u128 {
+        self.total_collected.saturating_sub(self.total_withdrawn)
+    }
+
+    // Returns the fees information, with the current balance of the proxy
+    pub fn info(&self, balance: u128) -> FeesInfo {
+        FeesInfo {
+            route_fees: self.route_fees.clone(),
+            total_collected: self.total_collected,
+            total_withdrawn: self.total_withdrawn,
+            balance
+        }
+    }
+}
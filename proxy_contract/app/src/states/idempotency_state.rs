@@ -0,0 +1,95 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    collections::{
+        BTreeMap,
+        HashMap
+    }
+};
+// Import the proxy routes
+use super::proxy_route::ProxyRoute;
+
+// Default number of blocks that the results are stored (about one hour)
+pub const DEFAULT_IDEMPOTENCY_TTL_BLOCKS: u32 = 1_200;
+
+// Struct to store the result of a command sent with an idempotency key
+pub struct StoredResult {
+    pub route: ProxyRoute,
+    pub stored_at: u32,
+    pub result: Vec<u8>
+}
+
+// State to store the (SCALE encoded) results of the commands sent with an
+// idempotency key, the results are stored by user and key for "ttl_blocks".
+// The keys are also stored by the block where they were stored, so the expired
+// results are removed from the oldest blocks without checking all the results
+pub struct IdempotencyState {
+    pub ttl_blocks: u32,
+    pub results: HashMap<(ActorId, H256), StoredResult>,
+    expirations: BTreeMap<u32, Vec<(ActorId, H256)>>
+}
+
+impl Default for IdempotencyState {
+    fn default() -> Self {
+        Self {
+            ttl_blocks: DEFAULT_IDEMPOTENCY_TTL_BLOCKS,
+            results: HashMap::new(),
+            expirations: BTreeMap::new()
+        }
+    }
+}
+
+impl IdempotencyState {
+    // Returns the result stored with the key of the user, if it has not expired
+    pub fn get(&self, user: ActorId, key: H256, block: u32) -> Option<&StoredResult> {
+        self.results
+            .get(&(user, key))
+            .filter(|stored| block.saturating_sub(stored.stored_at) < self.ttl_blocks)
+    }
+
+    // Method to store the result of a command, the results of the blocks that
+    // expired are removed
+    pub fn store(
+        &mut self,
+        user: ActorId,
+        key: H256,
+        route: ProxyRoute,
+        result: Vec<u8>,
+        block: u32
+    ) {
+        self.remove_expired(block);
+
+        if self.ttl_blocks == 0 {
+            return;
+        }
+
+        self.results.insert((user, key), StoredResult {
+            route,
+            stored_at: block,
+            result
+        });
+        self.expirations
+            .entry(block)
+            .or_default()
+            .push((user, key));
+    }
+
+    // Method to remove the results of the oldest blocks until a block that
+    // has not expired is found
+    fn remove_expired(&mut self, block: u32) {
+        while let Some(entry) = self.expirations.first_entry() {
+            let stored_at = *entry.key();
+
+            if block.saturating_sub(stored_at) < self.ttl_blocks {
+                break;
+            }
+
+            for key in entry.remove() {
+                // The key could be stored again in a later block
+                if self.results.get(&key).is_some_and(|stored| stored.stored_at == stored_at) {
+                    self.results.remove(&key);
+                }
+            }
+        }
+    }
+}
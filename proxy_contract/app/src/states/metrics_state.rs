@@ -0,0 +1,75 @@
+// Necesary crates
+use sails_rs::prelude::*;
+// Import the routes and targets of the proxy
+use super::proxy_route::{
+    ProxyRoute,
+    ProxyTarget
+};
+
+// Struct to store the counters of the calls made to a target or route
+#[derive(Default, PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallMetrics {
+    pub calls: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub last_call_block: Option<u32>
+}
+
+impl CallMetrics {
+    // Method to count a new call
+    pub fn record(&mut self, success: bool, block: u32) {
+        self.calls = self.calls.saturating_add(1);
+
+        if success {
+            self.successes = self.successes.saturating_add(1);
+        } else {
+            self.failures = self.failures.saturating_add(1);
+        }
+
+        self.last_call_block = Some(block);
+    }
+}
+
+// Struct that stores the metrics of the proxy, per target and per route.
+// It is also sent to the user when the metrics are queried.
+#[derive(Default, PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProxyMetrics {
+    pub targets: Vec<(ProxyTarget, CallMetrics)>,
+    pub routes: Vec<(ProxyRoute, CallMetrics)>
+}
+
+impl ProxyMetrics {
+    // Method to count a call to a route (and its target), it stores if
+    // the call was successful and the block where the call was made
+    pub fn record(&mut self, route: ProxyRoute, success: bool, block: u32) {
+        let target = route.target();
+
+        match self.targets.iter_mut().find(|(key, _)| *key == target) {
+            Some((_, metrics)) => metrics.record(success, block),
+            None => {
+                let mut metrics = CallMetrics::default();
+                metrics.record(success, block);
+                self.targets.push((target, metrics));
+            }
+        }
+
+        match self.routes.iter_mut().find(|(key, _)| *key == route) {
+            Some((_, metrics)) => metrics.record(success, block),
+            None => {
+                let mut metrics = CallMetrics::default();
+                metrics.record(success, block);
+                self.routes.push((route, metrics));
+            }
+        }
+    }
+
+    // Method to clear all the counters
+    pub fn reset(&mut self) {
+        self.targets.clear();
+        self.routes.clear();
+    }
+}
@@ -1,2 +1,20 @@
 // Module to export the proxy state
 pub mod proxy_state;
+// Module to export the routes and targets of the proxy
+pub mod proxy_route;
+// Module to export the metrics of the proxied calls
+pub mod metrics_state;
+// Module to export the rate limits of the proxied calls
+pub mod rate_limit_state;
+// Module to export the fees charged by the proxy
+pub mod fees_state;
+// Module to export the prepaid credit of the users
+pub mod credits_state;
+// Module to export the nonces of the relayed operations
+pub mod relay_state;
+// Module to export the session keys of the users
+pub mod session_state;
+// Module to export the results stored with idempotency keys
+pub mod idempotency_state;
+// Module to export the snapshots used to migrate the proxy state
+pub mod snapshot_state;
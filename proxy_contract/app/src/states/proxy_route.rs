@@ -0,0 +1,6 @@
+// Necesary crates
+use sails_rs::prelude::*;
+
+// The targets and routes of the proxy are generated by the build script
+// (app/build.rs) from the targets that are set in it
+include!(concat!(env!("OUT_DIR"), "/proxy_route.rs"));
@@ -1,31 +1,123 @@
 // Necesary crates
 use sails_rs::prelude::*;
+// Import the metrics, rate limits, fees, credits, relay nonces, session
+// keys, idempotency results and snapshots of the proxy
+use super::{
+    metrics_state::ProxyMetrics,
+    rate_limit_state::RateLimitState,
+    fees_state::FeesState,
+    credits_state::CreditsState,
+    relay_state::RelayState,
+    session_state::SessionsState,
+    idempotency_state::IdempotencyState,
+    snapshot_state::ProxyStateSnapshot,
+    proxy_route::ProxyTarget
+};
 
-// Set the Proxy state to store the traffic light contract and
-// ping contract id
+// Set the Proxy state to store the ids of the target contracts (only
+// the targets that are enabled in the build), "on_behalf_of" has the
+// targets that trust the proxy (the commands are sent on behalf of the users)
 #[derive(Default)]
 pub struct ProxyState {
     pub admins: Vec<ActorId>,
-    pub traffic_light_contract_id: Option<ActorId>,
-    pub ping_contract_id: Option<ActorId>,
+    pub contract_ids: Vec<(ProxyTarget, ActorId)>,
+    pub on_behalf_of: Vec<ProxyTarget>,
+    pub metrics: ProxyMetrics,
+    pub rate_limits: RateLimitState,
+    pub fees: FeesState,
+    pub credits: CreditsState,
+    pub relay: RelayState,
+    pub sessions: SessionsState,
+    pub idempotency: IdempotencyState,
+    pub import_locked: bool,
 }
 
 // Impl to set related functions to the state struct
 impl ProxyState {
     // Related function to create a new instance of ProxyState
     pub fn new(
-        traffic_light_id: Option<ActorId>, 
-        ping_id: Option<ActorId>,
-        admin: ActorId
+        admin: ActorId,
+        contract_ids: Vec<(ProxyTarget, ActorId)>
     ) -> Self {
         Self {
             admins: vec![admin],
-            traffic_light_contract_id: traffic_light_id,
-            ping_contract_id: ping_id 
+            contract_ids,
+            on_behalf_of: Vec::new(),
+            metrics: ProxyMetrics::default(),
+            rate_limits: RateLimitState::default(),
+            fees: FeesState::default(),
+            credits: CreditsState::default(),
+            relay: RelayState::default(),
+            sessions: SessionsState::default(),
+            idempotency: IdempotencyState::default(),
+            import_locked: false
         }
     }
 
     pub fn is_admin(&self, address: ActorId) -> bool {
         self.admins.contains(&address)
     }
+
+    // Method to get the id of a target contract
+    pub fn contract_id(&self, target: ProxyTarget) -> Option<ActorId> {
+        self.contract_ids
+            .iter()
+            .find(|(contract_target, _)| *contract_target == target)
+            .map(|(_, contract_id)| *contract_id)
+    }
+
+    // Method to set the id of a target contract
+    pub fn set_contract_id(&mut self, target: ProxyTarget, contract_id: ActorId) {
+        match self.contract_ids.iter_mut().find(|(contract_target, _)| *contract_target == target) {
+            Some((_, current_id)) => *current_id = contract_id,
+            None => self.contract_ids.push((target, contract_id))
+        }
+    }
+
+    // Method to get if the commands are sent to the target on behalf of the users,
+    // if not, the plain commands are sent (the proxy is the caller of the target)
+    pub fn sends_on_behalf_of(&self, target: ProxyTarget) -> bool {
+        self.on_behalf_of.contains(&target)
+    }
+
+    // Method to set if the commands are sent to the target on behalf of the users
+    pub fn set_on_behalf_of(&mut self, target: ProxyTarget, enabled: bool) {
+        self.on_behalf_of.retain(|on_behalf_of_target| *on_behalf_of_target != target);
+
+        if enabled {
+            self.on_behalf_of.push(target);
+        }
+    }
+
+    // Method to get the snapshot with the configuration of the proxy
+    pub fn snapshot(&self) -> ProxyStateSnapshot {
+        ProxyStateSnapshot {
+            admins: self.admins.clone(),
+            contract_ids: self.contract_ids.clone(),
+            on_behalf_of: self.on_behalf_of.clone(),
+            rate_limits: self.rate_limits.limits.clone(),
+            rate_limit_exempt: self.rate_limits.exempt.clone(),
+            route_fees: self.fees.route_fees.clone(),
+            idempotency_ttl_blocks: self.idempotency.ttl_blocks
+        }
+    }
+
+    // Method to restore the configuration of the proxy from a snapshot, the
+    // import is locked after it, so it can only be done once
+    pub fn restore(&mut self, snapshot: ProxyStateSnapshot) {
+        self.admins = snapshot.admins;
+        self.contract_ids = snapshot.contract_ids;
+        self.on_behalf_of = snapshot.on_behalf_of;
+        self.rate_limits.limits = snapshot.rate_limits;
+        self.rate_limits.exempt = snapshot.rate_limit_exempt;
+        self.fees.route_fees = snapshot.route_fees;
+        self.idempotency.ttl_blocks = snapshot.idempotency_ttl_blocks;
+        self.lock_import();
+    }
+
+    // Method to lock the import of snapshots, it is called by every command that
+    // changes the state, so an import can't overwrite a proxy that is in use
+    pub fn lock_import(&mut self) {
+        self.import_locked = true;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,160 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    collections::{
+        BTreeMap,
+        HashMap
+    }
+};
+// Import the proxy routes
+use super::proxy_route::ProxyRoute;
+
+// Struct to set the limit of a route: at most "max_calls" commands
+// per caller in a window of "window_blocks" blocks
+#[derive(PartialEq, Clone, Copy, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RateLimit {
+    pub max_calls: u32,
+    pub window_blocks: u32
+}
+
+// Struct to send the rate limits configuration to the user
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RateLimitsConfig {
+    pub limits: Vec<(ProxyRoute, RateLimit)>,
+    pub exempt: Vec<ActorId>
+}
+
+// State to store the rate limits of the routes, the callers that are exempt
+// from the limits and the blocks of the last calls of each caller
+#[derive(Default)]
+pub struct RateLimitState {
+    pub limits: Vec<(ProxyRoute, RateLimit)>,
+    pub exempt: Vec<ActorId>,
+    pub calls: HashMap<(ActorId, ProxyRoute), Vec<u32>>,
+    // Blocks where the calls leave their window, with the caller and the route of
+    // each call, so the windows of the callers that don't call again are removed
+    expirations: BTreeMap<u32, Vec<(ActorId, ProxyRoute)>>
+}
+
+impl RateLimitState {
+    // Returns the limit of the route, if it was set
+    pub fn limit(&self, route: ProxyRoute) -> Option<RateLimit> {
+        self.limits
+            .iter()
+            .find(|(key, _)| *key == route)
+            .map(|(_, limit)| *limit)
+    }
+
+    // Method to set (or replace) the limit of a route
+    pub fn set_limit(&mut self, route: ProxyRoute, limit: RateLimit) {
+        match self.limits.iter_mut().find(|(key, _)| *key == route) {
+            Some((_, current_limit)) => *current_limit = limit,
+            None => self.limits.push((route, limit))
+        }
+
+        // The calls stored with the old limit are cleared
+        self.calls.retain(|(_, key), _| *key != route);
+    }
+
+    // Method to remove the limit of a route, returns false if the
+    // route did not have a limit
+    pub fn remove_limit(&mut self, route: ProxyRoute) -> bool {
+        let Some(index) = self.limits.iter().position(|(key, _)| *key == route) else {
+            return false;
+        };
+
+        self.limits.remove(index);
+        self.calls.retain(|(_, key), _| *key != route);
+
+        true
+    }
+
+    pub fn is_exempt(&self, address: ActorId) -> bool {
+        self.exempt.contains(&address)
+    }
+
+    // Method to check if the caller can send a command to the route in the given
+    // block, if the caller can send it, the call is stored. If the caller exceeds
+    // the limit, it returns the block where the caller may try again.
+    // The windows of all the callers whose calls expired are removed first.
+    pub fn check_and_record(
+        &mut self,
+        caller: ActorId,
+        route: ProxyRoute,
+        block: u32
+    ) -> Result<(), u32> {
+        self.remove_expired_windows(block);
+
+        let Some(limit) = self.limit(route) else {
+            return Ok(());
+        };
+
+        if self.is_exempt(caller) {
+            return Ok(());
+        }
+
+        let calls = self.calls
+            .entry((caller, route))
+            .or_default();
+
+        // Only the calls inside the window are kept (sliding window)
+        calls.retain(|call_block| block.saturating_sub(*call_block) < limit.window_blocks);
+
+        if calls.len() as u32 >= limit.max_calls {
+            // The oldest call in the window will leave it at this block
+            let retry_after_block = calls
+                .first()
+                .map(|oldest_block| oldest_block.saturating_add(limit.window_blocks))
+                .unwrap_or(block);
+
+            return Err(retry_after_block);
+        }
+
+        calls.push(block);
+
+        self.expirations
+            .entry(block.saturating_add(limit.window_blocks))
+            .or_default()
+            .push((caller, route));
+
+        Ok(())
+    }
+
+    // Helper method to remove the calls that left their window, each window is
+    // checked in the block where one of its calls leaves it, and it is removed if
+    // it has no calls left (the newer calls have their own expiration)
+    fn remove_expired_windows(&mut self, block: u32) {
+        while self.expirations.first_key_value().is_some_and(|(expiration, _)| *expiration <= block) {
+            let Some((_, windows)) = self.expirations.pop_first() else {
+                break;
+            };
+
+            for key in windows {
+                let window_blocks = self.limit(key.1)
+                    .map(|limit| limit.window_blocks)
+                    .unwrap_or(0);
+                let Some(calls) = self.calls.get_mut(&key) else {
+                    continue;
+                };
+
+                calls.retain(|call_block| block.saturating_sub(*call_block) < window_blocks);
+
+                if calls.is_empty() {
+                    self.calls.remove(&key);
+                }
+            }
+        }
+    }
+
+    // Returns the configuration of the rate limits
+    pub fn config(&self) -> RateLimitsConfig {
+        RateLimitsConfig {
+            limits: self.limits.clone(),
+            exempt: self.exempt.clone()
+        }
+    }
+}
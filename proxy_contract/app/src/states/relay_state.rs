@@ -0,0 +1,36 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    collections::HashMap
+};
+
+// State to store the next nonce of each user that sends signed
+// operations through a relayer (replay protection)
+#[derive(Default)]
+pub struct RelayState {
+    pub nonces: HashMap<ActorId, u64>
+}
+
+impl RelayState {
+    // Returns the nonce that the next operation of the user must use
+    pub fn nonce(&self, user: ActorId) -> u64 {
+        self.nonces
+            .get(&user)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // Method to use the nonce of the user, it returns the expected
+    // nonce if the given nonce is not the next one
+    pub fn use_nonce(&mut self, user: ActorId, nonce: u64) -> Result<(), u64> {
+        let expected = self.nonce(user);
+
+        if nonce != expected {
+            return Err(expected);
+        }
+
+        self.nonces.insert(user, expected.saturating_add(1));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    collections::HashMap
+};
+// Import the proxy routes
+use super::proxy_route::ProxyRoute;
+
+// Struct to store the data of a session key: the owner that registered it,
+// the routes that it can call, the block where it expires, the calls that it
+// already sent and if the session key accepted the owner (the key is not used
+// until it is accepted by the account of the key)
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SessionKey {
+    pub owner: ActorId,
+    pub scope: Vec<ProxyRoute>,
+    pub expires_at: u32,
+    pub max_calls: u32,
+    pub calls: u32,
+    pub accepted: bool
+}
+
+// Enum to set the errors when a session key is used
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum SessionKeyError {
+    Expired(u32),
+    RouteNotInScope(ProxyRoute),
+    CallsExhausted(u32)
+}
+
+// State to store the session keys, each key is stored with its data
+#[derive(Default)]
+pub struct SessionsState {
+    pub sessions: HashMap<ActorId, SessionKey>
+}
+
+impl SessionsState {
+    // Returns if the address is an accepted session key
+    pub fn is_session_key(&self, address: ActorId) -> bool {
+        self.sessions
+            .get(&address)
+            .is_some_and(|session| session.accepted)
+    }
+
+    // Returns the session keys registered by the owner
+    pub fn owner_session_keys(&self, owner: ActorId) -> Vec<(ActorId, SessionKey)> {
+        self.sessions
+            .iter()
+            .filter(|(_, session)| session.owner == owner)
+            .map(|(key, session)| (*key, session.clone()))
+            .collect()
+    }
+
+    // Method to get the user that will be used for a command sent by the caller:
+    // - If the caller is not an accepted session key, the caller is the user.
+    // - If the caller is an accepted session key, the owner is the user if the
+    //   session key can call the route (the calls are checked and counted with
+    //   "record_call", so the stored results can be returned without them).
+    // Expired session keys are removed.
+    pub fn resolve_user(
+        &mut self,
+        caller: ActorId,
+        route: ProxyRoute,
+        block: u32
+    ) -> Result<ActorId, SessionKeyError> {
+        let Some(session) = self.sessions
+            .get(&caller)
+            .filter(|session| session.accepted)
+        else {
+            return Ok(caller);
+        };
+
+        if block > session.expires_at {
+            let expires_at = session.expires_at;
+            self.sessions.remove(&caller);
+            return Err(SessionKeyError::Expired(expires_at));
+        }
+
+        if !session.scope.contains(&route) {
+            return Err(SessionKeyError::RouteNotInScope(route));
+        }
+
+        Ok(session.owner)
+    }
+
+    // Method to count a command sent by the caller, if it is an accepted session
+    // key, it returns an error if the session key has no calls left
+    pub fn record_call(&mut self, caller: ActorId) -> Result<(), SessionKeyError> {
+        let Some(session) = self.sessions
+            .get_mut(&caller)
+            .filter(|session| session.accepted)
+        else {
+            return Ok(());
+        };
+
+        if session.calls >= session.max_calls {
+            return Err(SessionKeyError::CallsExhausted(session.max_calls));
+        }
+
+        session.calls += 1;
+
+        Ok(())
+    }
+}
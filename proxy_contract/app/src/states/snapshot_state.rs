@@ -0,0 +1,122 @@
+// Necesary crates
+use sails_rs::{
+    prelude::*,
+    scale_codec::DecodeAll
+};
+use sha2::{
+    Digest,
+    Sha256
+};
+// Import the proxy routes and the rate limits
+use super::{
+    proxy_route::{
+        ProxyRoute,
+        ProxyTarget
+    },
+    rate_limit_state::RateLimit
+};
+
+// Version of the snapshot schema, it has to be changed each time that the
+// ProxyStateSnapshot struct changes, so old snapshots are rejected
+pub const PROXY_STATE_SCHEMA_VERSION: u32 = 3;
+
+// Struct with the configuration of the proxy that is moved between deployments
+// (the users data, like credits, metrics or sessions, are not included)
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProxyStateSnapshot {
+    pub admins: Vec<ActorId>,
+    pub contract_ids: Vec<(ProxyTarget, ActorId)>,
+    pub on_behalf_of: Vec<ProxyTarget>,
+    pub rate_limits: Vec<(ProxyRoute, RateLimit)>,
+    pub rate_limit_exempt: Vec<ActorId>,
+    pub route_fees: Vec<(ProxyRoute, u128)>,
+    pub idempotency_ttl_blocks: u32
+}
+
+impl ProxyStateSnapshot {
+    // Returns if the admins and the targets of the contract ids are unique, and
+    // if the targets are enabled in the build
+    pub fn is_valid(&self) -> bool {
+        let unique_admins = self.admins
+            .iter()
+            .enumerate()
+            .all(|(index, admin)| !self.admins[..index].contains(admin));
+        let unique_targets = self.contract_ids
+            .iter()
+            .enumerate()
+            .all(|(index, (target, _))| {
+                self.contract_ids[..index]
+                    .iter()
+                    .all(|(previous_target, _)| previous_target != target)
+            });
+
+        let enabled_targets = self.contract_ids
+            .iter()
+            .map(|(target, _)| target)
+            .chain(&self.on_behalf_of)
+            .all(ProxyTarget::is_enabled);
+
+        unique_admins && unique_targets && enabled_targets
+    }
+}
+
+// Struct that is sent to the user when the state is exported, "data" is the
+// SCALE encoded ProxyStateSnapshot
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProxyStateExport {
+    pub schema_version: u32,
+    pub checksum: [u8; 32],
+    pub data: Vec<u8>
+}
+
+// Enum to set the reasons why a snapshot can't be imported
+pub enum SnapshotError {
+    IncompatibleSchemaVersion(u32),
+    InvalidChecksum,
+    InvalidData
+}
+
+impl ProxyStateExport {
+    // Related function to create the export of a snapshot
+    pub fn new(snapshot: &ProxyStateSnapshot) -> Self {
+        let data = snapshot.encode();
+
+        Self {
+            schema_version: PROXY_STATE_SCHEMA_VERSION,
+            checksum: checksum(PROXY_STATE_SCHEMA_VERSION, &data),
+            data
+        }
+    }
+
+    // Method to get the snapshot, it checks the schema version, the checksum and
+    // the data (all the bytes are decoded and there are no repeated admins or
+    // targets, like in the snapshots exported by a proxy)
+    pub fn snapshot(&self) -> Result<ProxyStateSnapshot, SnapshotError> {
+        if self.schema_version != PROXY_STATE_SCHEMA_VERSION {
+            return Err(SnapshotError::IncompatibleSchemaVersion(self.schema_version));
+        }
+
+        if self.checksum != checksum(self.schema_version, &self.data) {
+            return Err(SnapshotError::InvalidChecksum);
+        }
+
+        ProxyStateSnapshot::decode_all(&mut self.data.as_slice())
+            .ok()
+            .filter(ProxyStateSnapshot::is_valid)
+            .ok_or(SnapshotError::InvalidData)
+    }
+}
+
+// Helper function to get the sha256 hash of the schema version and the data
+fn checksum(schema_version: u32, data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+
+    hasher.update(schema_version.to_le_bytes());
+    hasher.update(data);
+
+    hasher.finalize().into()
+}
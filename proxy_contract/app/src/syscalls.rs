@@ -0,0 +1,13 @@
+// Syscalls used by the program, they are the ones of gstd, or their mocks if
+// the "mocks" feature is enabled (it is only enabled by the tests of the app,
+// see the dev-dependencies in Cargo.toml), so the services run with "cargo test"
+#[cfg(not(feature = "mocks"))]
+pub use sails_rs::gstd::{
+    exec,
+    msg
+};
+#[cfg(feature = "mocks")]
+pub use crate::mocks::syscalls::{
+    exec,
+    msg
+};
@@ -0,0 +1,516 @@
+// Unit tests of the caller services, they run on the host with "cargo test": the
+// clients use the mock remoting and the syscalls (sender, value, block) are mocked.
+// The tests need the default features (all the caller services).
+use sails_rs::{
+    prelude::*,
+    cell::RefCell,
+    errors::RtlError
+};
+use app::{
+    clients::{
+        ping_client::{
+            CallsPage,
+            Ping as PingClient,
+            PingEnum
+        },
+        traffic_light_client::{
+            IoTrafficLightState,
+            TrafficLight as TrafficLightClient,
+            TrafficLightEvent
+        }
+    },
+    mocks::{
+        remoting::MockRemoting,
+        syscalls
+    },
+    services::{
+        proxy_ping_caller_service::{
+            ProxyPingCallerError,
+            ProxyPingCallerEvent,
+            ProxyPingCallerService
+        },
+        proxy_taffic_light_caller_service::{
+            ProxyTrafficLightCallerError,
+            ProxyTrafficLightCallerEvent,
+            ProxyTrafficLightCallerService
+        }
+    },
+    states::{
+        metrics_state::CallMetrics,
+        proxy_route::{
+            ProxyRoute,
+            ProxyTarget
+        },
+        proxy_state::ProxyState,
+        rate_limit_state::RateLimit,
+        session_state::{
+            SessionKey,
+            SessionKeyError
+        }
+    }
+};
+
+const ADMIN: u64 = 1;
+const USER: u64 = 2;
+const SESSION_KEY: u64 = 3;
+const PING_CONTRACT: u64 = 10;
+const TRAFFIC_LIGHT_CONTRACT: u64 = 11;
+const BLOCK: u32 = 100;
+
+// Proxy state and clients that are used to create the caller services
+struct Setup {
+    state: RefCell<ProxyState>,
+    remoting: MockRemoting,
+    ping_client: RefCell<PingClient<MockRemoting>>,
+    traffic_light_client: RefCell<TrafficLightClient<MockRemoting>>
+}
+
+impl Setup {
+    fn new(contract_ids: Vec<(ProxyTarget, ActorId)>) -> Self {
+        syscalls::reset();
+        syscalls::set_source(USER.into());
+        syscalls::set_block_height(BLOCK);
+
+        let remoting = MockRemoting::new();
+        let mut state = ProxyState::new(ADMIN.into(), contract_ids);
+
+        // The targets trust the proxy, so the commands are sent on behalf of the users
+        state.set_on_behalf_of(ProxyTarget::PingContract, true);
+        state.set_on_behalf_of(ProxyTarget::TrafficLightContract, true);
+
+        Self {
+            state: RefCell::new(state),
+            ping_client: RefCell::new(PingClient::new(remoting.clone())),
+            traffic_light_client: RefCell::new(TrafficLightClient::new(remoting.clone())),
+            remoting
+        }
+    }
+
+    fn ping_caller(&self) -> ProxyPingCallerService<'_, PingClient<MockRemoting>> {
+        ProxyPingCallerService::new(&self.state, self.ping_client.borrow_mut())
+    }
+
+    fn traffic_light_caller(&self) -> ProxyTrafficLightCallerService<'_, TrafficLightClient<MockRemoting>> {
+        ProxyTrafficLightCallerService::new(&self.state, self.traffic_light_client.borrow_mut())
+    }
+
+    fn route_metrics(&self, route: ProxyRoute) -> CallMetrics {
+        self.state
+            .borrow()
+            .metrics
+            .routes
+            .iter()
+            .find(|(metrics_route, _)| *metrics_route == route)
+            .map(|(_, metrics)| metrics.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn setup() -> Setup {
+    Setup::new(vec![
+        (ProxyTarget::PingContract, PING_CONTRACT.into()),
+        (ProxyTarget::TrafficLightContract, TRAFFIC_LIGHT_CONTRACT.into())
+    ])
+}
+
+#[tokio::test]
+async fn command_is_sent_on_behalf_of_the_user() {
+    let setup = setup();
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    let calls = setup.remoting.calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].target, ActorId::from(PING_CONTRACT));
+    assert_eq!(calls[0].method, "PingOnBehalfOf");
+    assert_eq!(calls[0].decode_args::<ActorId>(), Some(ActorId::from(USER)));
+    assert!(!calls[0].is_query);
+
+    let metrics = setup.route_metrics(ProxyRoute::CallPing);
+    assert_eq!((metrics.calls, metrics.successes, metrics.last_call_block), (1, 1, Some(BLOCK)));
+}
+
+#[tokio::test]
+async fn plain_command_is_sent_if_the_target_does_not_trust_the_proxy() {
+    let setup = setup();
+    setup.state.borrow_mut().set_on_behalf_of(ProxyTarget::PingContract, false);
+    setup.remoting.reply("Ping", "Ping", PingEnum::Pong);
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    let calls = setup.remoting.calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method, "Ping");
+    assert!(calls[0].args.is_empty());
+}
+
+#[tokio::test]
+async fn command_fails_if_the_contract_id_is_not_set() {
+    let setup = Setup::new(vec![]);
+
+    let event = setup.ping_caller().call_pong().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractIdNotSet)
+    );
+
+    assert!(setup.remoting.calls().is_empty());
+    assert_eq!(setup.route_metrics(ProxyRoute::CallPong).failures, 1);
+}
+
+#[tokio::test]
+async fn contract_errors_are_returned_and_not_charged() {
+    let setup = setup();
+    setup.state.borrow_mut().fees.set_fee(ProxyRoute::CallPing, 10);
+    setup.state.borrow_mut().credits.deposit(USER.into(), 25);
+    setup.remoting.fail("Ping", "PingOnBehalfOf", RtlError::ReplyIsMissing.into());
+
+    let event = setup.ping_caller().call_ping().await;
+    assert!(
+        matches!(event, ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractError(_))),
+        "{event:?}"
+    );
+
+    assert_eq!(setup.state.borrow().credits.balance(USER.into()), 25);
+    assert_eq!(setup.route_metrics(ProxyRoute::CallPing).failures, 1);
+}
+
+#[tokio::test]
+async fn rate_limited_commands_are_not_sent() {
+    let setup = setup();
+    setup.state.borrow_mut().rate_limits.set_limit(
+        ProxyRoute::CallPing,
+        RateLimit { max_calls: 1, window_blocks: 10 }
+    );
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::RateLimitExceeded {
+            retry_after_block: BLOCK + 10
+        })
+    );
+
+    assert_eq!(setup.remoting.calls().len(), 1);
+}
+
+#[tokio::test]
+async fn fee_is_charged_from_the_attached_value() {
+    let setup = setup();
+    setup.state.borrow_mut().fees.set_fee(ProxyRoute::CallPing, 10);
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+
+    // The value attached is not enough
+    syscalls::set_value(5);
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::InsufficientFee {
+            required: 10,
+            attached: 5
+        })
+    );
+    assert!(setup.remoting.calls().is_empty());
+
+    // The rest of the value is returned to the user
+    syscalls::set_value(15);
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    assert_eq!(
+        syscalls::sent_values(),
+        vec![(ActorId::from(USER), 5), (ActorId::from(USER), 5)]
+    );
+    assert_eq!(setup.state.borrow().fees.total_collected, 10);
+}
+
+#[tokio::test]
+async fn failed_refunds_are_added_to_the_credit() {
+    let setup = setup();
+    setup.state.borrow_mut().fees.set_fee(ProxyRoute::CallPing, 10);
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+    syscalls::set_value(15);
+    syscalls::set_sends_fail(true);
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    assert!(syscalls::sent_values().is_empty());
+    assert_eq!(setup.state.borrow().credits.balance(USER.into()), 5);
+    assert_eq!(setup.state.borrow().fees.total_collected, 10);
+}
+
+#[tokio::test]
+async fn fee_is_charged_from_the_credit() {
+    let setup = setup();
+    setup.state.borrow_mut().fees.set_fee(ProxyRoute::CallPong, 10);
+    setup.remoting.reply("Ping", "PongOnBehalfOf", PingEnum::Ping);
+
+    let event = setup.ping_caller().call_pong().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::InsufficientCredit {
+            required: 10,
+            balance: 0
+        })
+    );
+
+    setup.state.borrow_mut().credits.deposit(USER.into(), 25);
+
+    let event = setup.ping_caller().call_pong().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Ping));
+
+    assert_eq!(setup.state.borrow().credits.balance(USER.into()), 15);
+    assert!(syscalls::sent_values().is_empty());
+}
+
+#[tokio::test]
+async fn credit_is_not_charged_partially() {
+    let setup = setup();
+    setup.state.borrow_mut().fees.set_fee(ProxyRoute::CallPing, 10);
+    setup.state.borrow_mut().credits.deposit(USER.into(), 5);
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::InsufficientCredit {
+            required: 10,
+            balance: 5
+        })
+    );
+
+    assert!(setup.remoting.calls().is_empty());
+    assert_eq!(setup.state.borrow().credits.balance(USER.into()), 5);
+    assert_eq!(setup.state.borrow().fees.total_collected, 0);
+}
+
+#[tokio::test]
+async fn session_keys_call_on_behalf_of_their_owner() {
+    let setup = setup();
+    setup.state.borrow_mut().sessions.sessions.insert(SESSION_KEY.into(), SessionKey {
+        owner: USER.into(),
+        scope: vec![ProxyRoute::CallPing],
+        expires_at: BLOCK + 10,
+        max_calls: 1,
+        calls: 0,
+        accepted: true
+    });
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+    syscalls::set_source(SESSION_KEY.into());
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+    assert_eq!(setup.remoting.calls()[0].decode_args::<ActorId>(), Some(ActorId::from(USER)));
+
+    let event = setup.ping_caller().call_pong().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::SessionKey(
+            SessionKeyError::RouteNotInScope(ProxyRoute::CallPong)
+        ))
+    );
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::SessionKey(
+            SessionKeyError::CallsExhausted(1)
+        ))
+    );
+
+    syscalls::set_block_height(BLOCK + 11);
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::SessionKey(
+            SessionKeyError::Expired(BLOCK + 10)
+        ))
+    );
+
+    assert_eq!(setup.remoting.calls().len(), 1);
+}
+
+#[tokio::test]
+async fn session_keys_that_were_not_accepted_are_not_used() {
+    let setup = setup();
+    setup.state.borrow_mut().sessions.sessions.insert(SESSION_KEY.into(), SessionKey {
+        owner: USER.into(),
+        scope: vec![ProxyRoute::CallPing],
+        expires_at: BLOCK + 10,
+        max_calls: 1,
+        calls: 0,
+        accepted: false
+    });
+
+    let user = setup.state
+        .borrow_mut()
+        .sessions
+        .resolve_user(SESSION_KEY.into(), ProxyRoute::CallPing, BLOCK);
+    assert_eq!(user, Ok(ActorId::from(SESSION_KEY)));
+
+    // The command is sent as the caller, not as the owner of the proposed key
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+    syscalls::set_source(SESSION_KEY.into());
+
+    let event = setup.ping_caller().call_ping().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+    assert_eq!(setup.remoting.calls()[0].decode_args::<ActorId>(), Some(ActorId::from(SESSION_KEY)));
+    assert_eq!(setup.state.borrow().sessions.sessions[&ActorId::from(SESSION_KEY)].calls, 0);
+}
+
+#[tokio::test]
+async fn idempotency_key_returns_the_stored_result() {
+    let setup = setup();
+    let idempotency_key = H256::repeat_byte(1);
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+
+    let first_event = setup.ping_caller().call_ping_idempotent(idempotency_key).await;
+    assert_eq!(first_event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    // The command is not sent again (there is no other scripted reply)
+    let event = setup.ping_caller().call_ping_idempotent(idempotency_key).await;
+    assert_eq!(event, first_event);
+    assert_eq!(setup.remoting.calls().len(), 1);
+
+    let event = setup.ping_caller().call_pong_idempotent(idempotency_key).await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::IdempotencyKeyReused(ProxyRoute::CallPing))
+    );
+
+    // The results expire after the TTL
+    syscalls::set_block_height(BLOCK + setup.state.borrow().idempotency.ttl_blocks);
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+
+    let event = setup.ping_caller().call_ping_idempotent(idempotency_key).await;
+    assert_eq!(event, first_event);
+    assert_eq!(setup.remoting.calls().len(), 2);
+}
+
+#[tokio::test]
+async fn stored_results_do_not_use_the_calls_of_session_keys() {
+    let setup = setup();
+    let idempotency_key = H256::repeat_byte(1);
+    setup.state.borrow_mut().sessions.sessions.insert(SESSION_KEY.into(), SessionKey {
+        owner: USER.into(),
+        scope: vec![ProxyRoute::CallPing],
+        expires_at: BLOCK + 10,
+        max_calls: 1,
+        calls: 0,
+        accepted: true
+    });
+    setup.remoting.reply("Ping", "PingOnBehalfOf", PingEnum::Pong);
+    syscalls::set_source(SESSION_KEY.into());
+
+    let first_event = setup.ping_caller().call_ping_idempotent(idempotency_key).await;
+    assert_eq!(first_event, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    // The session key has no calls left, but the stored result is returned
+    let event = setup.ping_caller().call_ping_idempotent(idempotency_key).await;
+    assert_eq!(event, first_event);
+    assert_eq!(setup.state.borrow().sessions.sessions[&ActorId::from(SESSION_KEY)].calls, 1);
+
+    let event = setup.ping_caller().call_ping_idempotent(H256::repeat_byte(2)).await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::SessionKey(
+            SessionKeyError::CallsExhausted(1)
+        ))
+    );
+}
+
+#[tokio::test]
+async fn queries_return_the_contract_state() {
+    let setup = setup();
+    let all_calls = vec![(ActorId::from(USER), PingEnum::Ping)];
+    setup.remoting.reply("Ping", "AllCalls", all_calls.clone());
+
+    let event = setup.ping_caller().call_all_calls().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractStateAllCalls(all_calls));
+
+    let calls = setup.remoting.calls();
+    assert_eq!((calls[0].method.as_str(), calls[0].is_query), ("AllCalls", true));
+
+    // Without a scripted reply, the query fails
+    let event = setup.ping_caller().call_last_who_call().await;
+    assert!(
+        matches!(event, ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractError(_))),
+        "{event:?}"
+    );
+
+    let setup = Setup::new(vec![]);
+    let event = setup.ping_caller().call_last_who_call().await;
+    assert_eq!(
+        event,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractIdNotSet)
+    );
+}
+
+#[tokio::test]
+async fn paginated_queries_send_their_arguments() {
+    let setup = setup();
+    let page = CallsPage {
+        calls: vec![(ActorId::from(USER), PingEnum::Pong)],
+        next_offset: Some(7)
+    };
+    setup.remoting.reply("Ping", "CallsByActor", page.clone());
+    setup.remoting.reply("Ping", "CallsCount", 12u32);
+
+    let event = setup.ping_caller().call_calls_by_actor(USER.into(), 5, 1).await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractStateCallsByActor(page));
+
+    let event = setup.ping_caller().call_calls_count().await;
+    assert_eq!(event, ProxyPingCallerEvent::PingContractStateCallsCount(12));
+
+    let calls = setup.remoting.calls();
+    assert_eq!((calls[0].method.as_str(), calls[0].is_query), ("CallsByActor", true));
+    assert_eq!(calls[0].decode_args::<(ActorId, u64, u32)>(), Some((USER.into(), 5, 1)));
+}
+
+#[tokio::test]
+async fn routes_of_other_targets_are_not_called() {
+    let setup = setup();
+    setup.remoting.reply("Ping", "PongOnBehalfOf", PingEnum::Ping);
+
+    let event = setup.ping_caller().call_route_as(ProxyRoute::CallGreen, USER.into(), None).await;
+    assert_eq!(event, None);
+
+    let event = setup.ping_caller().call_route_as(ProxyRoute::CallPong, USER.into(), None).await;
+    assert_eq!(event, Some(ProxyPingCallerEvent::PingContractResponse(PingEnum::Ping)));
+}
+
+#[tokio::test]
+async fn traffic_light_commands_and_queries() {
+    let setup = setup();
+    let state = IoTrafficLightState {
+        current_light: "Green".to_string(),
+        all_users: vec![(USER.into(), "Green".to_string())]
+    };
+    setup.remoting
+        .reply("TrafficLight", "GreenOnBehalfOf", TrafficLightEvent::Green)
+        .reply("TrafficLight", "TrafficLight", state.clone());
+
+    let event = setup.traffic_light_caller().call_green().await;
+    assert_eq!(event, ProxyTrafficLightCallerEvent::TrafficLightContractResponse(TrafficLightEvent::Green));
+
+    let event = setup.traffic_light_caller().call_traffic_light_state().await;
+    assert_eq!(event, ProxyTrafficLightCallerEvent::TrafficLightContractState(state));
+
+    let calls = setup.remoting.calls();
+    assert_eq!(calls[0].target, ActorId::from(TRAFFIC_LIGHT_CONTRACT));
+    assert_eq!(calls[0].decode_args::<ActorId>(), Some(ActorId::from(USER)));
+
+    let setup = Setup::new(vec![(ProxyTarget::PingContract, PING_CONTRACT.into())]);
+    let event = setup.traffic_light_caller().call_red().await;
+    assert_eq!(
+        event,
+        ProxyTrafficLightCallerEvent::Error(ProxyTrafficLightCallerError::TrafficLightContractIdNotSet)
+    );
+}
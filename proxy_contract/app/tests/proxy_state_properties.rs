@@ -0,0 +1,241 @@
+// Property tests of the ProxyState invariants, random sequences of ProxyService
+// commands are sent by random callers through the real service code, and the
+// invariants are checked after every step:
+// - The admins are unique and there is always at least one admin.
+// - The targets of the contract ids are unique.
+// - Only the admins can change the configuration (admins, targets, limits, fees).
+// - A state can only be imported before any other command changed the state.
+// The snapshots themselves are validated by ProxyStateExport (tests/state_import.rs).
+// When a sequence breaks an invariant, proptest shrinks it and prints the
+// smallest failing sequence (it is also saved in "proptest-regressions").
+use sails_rs::{
+    prelude::*,
+    cell::RefCell
+};
+use proptest::{
+    prelude::*,
+    sample::select
+};
+use app::{
+    mocks::syscalls,
+    services::proxy_service::{
+        ProxyEvent,
+        ProxyService
+    },
+    states::{
+        proxy_route::{
+            ProxyRoute,
+            ProxyTarget
+        },
+        proxy_state::ProxyState,
+        snapshot_state::{
+            ProxyStateExport,
+            ProxyStateSnapshot
+        }
+    }
+};
+
+const ADMIN: u64 = 1;
+// The callers and the addresses are taken from a small set of actors, so the
+// sequences repeat them (the admin is one of them)
+const ACTORS: u64 = 5;
+const PING_CONTRACT: u64 = 10;
+const TRAFFIC_LIGHT_CONTRACT: u64 = 11;
+
+// Command of the ProxyService, the actors are the numbers of the accounts
+#[derive(Clone, Debug)]
+enum Operation {
+    ChangeContractId(ProxyTarget, u64),
+    AddAdmin(u64),
+    ResetMetrics,
+    SetRateLimit(ProxyRoute, u32, u32),
+    RemoveRateLimit(ProxyRoute),
+    AddRateLimitExempt(u64),
+    RemoveRateLimitExempt(u64),
+    SetRouteFee(ProxyRoute, u128),
+    WithdrawFees(u64, u128),
+    SetIdempotencyTtl(u32),
+    ImportState {
+        admins: Vec<u64>,
+        contract_ids: Vec<(ProxyTarget, u64)>,
+        valid_checksum: bool
+    }
+}
+
+// Command sent by a caller
+#[derive(Clone, Debug)]
+struct Step {
+    caller: u64,
+    operation: Operation
+}
+
+fn actor() -> impl Strategy<Value = u64> {
+    1..=ACTORS
+}
+
+fn target() -> impl Strategy<Value = ProxyTarget> {
+    select(vec![ProxyTarget::PingContract, ProxyTarget::TrafficLightContract])
+}
+
+fn route() -> impl Strategy<Value = ProxyRoute> {
+    select(vec![ProxyRoute::CallPing, ProxyRoute::CallPong, ProxyRoute::CallGreen, ProxyRoute::CallRed])
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        (target(), actor()).prop_map(|(target, id)| Operation::ChangeContractId(target, id)),
+        actor().prop_map(Operation::AddAdmin),
+        Just(Operation::ResetMetrics),
+        (route(), 0..3u32, 0..3u32).prop_map(|(route, max_calls, window_blocks)| {
+            Operation::SetRateLimit(route, max_calls, window_blocks)
+        }),
+        route().prop_map(Operation::RemoveRateLimit),
+        actor().prop_map(Operation::AddRateLimitExempt),
+        actor().prop_map(Operation::RemoveRateLimitExempt),
+        (route(), 0..100u128).prop_map(|(route, fee)| Operation::SetRouteFee(route, fee)),
+        (actor(), 0..100u128).prop_map(|(to, amount)| Operation::WithdrawFees(to, amount)),
+        (0..10u32).prop_map(Operation::SetIdempotencyTtl),
+        (
+            prop::collection::vec(actor(), 0..4),
+            prop::collection::vec((target(), actor()), 0..4),
+            any::<bool>()
+        ).prop_map(|(admins, contract_ids, valid_checksum)| Operation::ImportState {
+            admins,
+            contract_ids,
+            valid_checksum
+        })
+    ]
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    (actor(), operation()).prop_map(|(caller, operation)| Step {
+        caller,
+        operation
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn invariants_hold_after_every_step(steps in prop::collection::vec(step(), 1..40)) {
+        syscalls::reset();
+
+        let state = RefCell::new(ProxyState::new(
+            ADMIN.into(),
+            vec![
+                (ProxyTarget::PingContract, PING_CONTRACT.into()),
+                (ProxyTarget::TrafficLightContract, TRAFFIC_LIGHT_CONTRACT.into())
+            ]
+        ));
+
+        check_invariants(&state.borrow())?;
+
+        let mut state_changed = false;
+
+        for (index, step) in steps.iter().enumerate() {
+            let before = state.borrow().snapshot();
+            let was_admin = state.borrow().is_admin(step.caller.into());
+
+            let event = run_step(&state, step);
+            let state = state.borrow();
+
+            check_invariants(&state)
+                .map_err(|error| TestCaseError::fail(format!("Step {index} ({step:?}): {error}")))?;
+
+            if !was_admin {
+                prop_assert!(
+                    matches!(event, ProxyEvent::Error(_)),
+                    "Step {index} ({step:?}): a non admin command succeeded: {event:?}"
+                );
+                prop_assert_eq!(
+                    &state.snapshot(),
+                    &before,
+                    "Step {} ({:?}): a non admin changed the configuration",
+                    index,
+                    step
+                );
+            }
+
+            if let (Operation::AddAdmin(new_admin), ProxyEvent::AdminAdded(_)) = (&step.operation, &event) {
+                prop_assert!(state.is_admin((*new_admin).into()), "Step {index} ({step:?}): the admin was not added");
+            }
+
+            if event == ProxyEvent::StateImported {
+                prop_assert!(!state_changed, "Step {index} ({step:?}): the state was imported after a change");
+            }
+
+            state_changed |= !matches!(event, ProxyEvent::Error(_));
+        }
+    }
+}
+
+// Sends the command of the step with the caller as the source of the message
+fn run_step(state: &RefCell<ProxyState>, step: &Step) -> ProxyEvent {
+    syscalls::set_source(step.caller.into());
+
+    let mut service = ProxyService::new(state.borrow_mut());
+
+    match step.operation.clone() {
+        Operation::ChangeContractId(target, id) => service.change_contract_id(target, id.into()),
+        Operation::AddAdmin(new_admin) => service.add_admin(new_admin.into()),
+        Operation::ResetMetrics => service.reset_metrics(),
+        Operation::SetRateLimit(route, max_calls, window_blocks) => {
+            service.set_rate_limit(route, max_calls, window_blocks)
+        },
+        Operation::RemoveRateLimit(route) => service.remove_rate_limit(route),
+        Operation::AddRateLimitExempt(address) => service.add_rate_limit_exempt(address.into()),
+        Operation::RemoveRateLimitExempt(address) => service.remove_rate_limit_exempt(address.into()),
+        Operation::SetRouteFee(route, fee) => service.set_route_fee(route, fee),
+        Operation::WithdrawFees(to, amount) => service.withdraw_fees(to.into(), amount),
+        Operation::SetIdempotencyTtl(ttl_blocks) => service.set_idempotency_ttl(ttl_blocks),
+        Operation::ImportState { admins, contract_ids, valid_checksum } => {
+            let snapshot = ProxyStateSnapshot {
+                admins: admins
+                    .into_iter()
+                    .map(ActorId::from)
+                    .collect(),
+                contract_ids: contract_ids
+                    .into_iter()
+                    .map(|(target, id)| (target, id.into()))
+                    .collect(),
+                on_behalf_of: Vec::new(),
+                rate_limits: Vec::new(),
+                rate_limit_exempt: Vec::new(),
+                route_fees: Vec::new(),
+                idempotency_ttl_blocks: 0
+            };
+
+            let mut export = ProxyStateExport::new(&snapshot);
+            if !valid_checksum {
+                export.checksum[0] ^= 1;
+            }
+
+            service.import_state(export)
+        }
+    }
+}
+
+fn check_invariants(state: &ProxyState) -> Result<(), TestCaseError> {
+    prop_assert!(!state.admins.is_empty(), "There are no admins");
+
+    for (index, admin) in state.admins.iter().enumerate() {
+        prop_assert!(
+            !state.admins[..index].contains(admin),
+            "The admin {:?} is repeated: {:?}",
+            admin,
+            state.admins
+        );
+    }
+
+    for (index, (target, _)) in state.contract_ids.iter().enumerate() {
+        prop_assert!(
+            state.contract_ids[..index].iter().all(|(previous, _)| previous != target),
+            "The target {:?} is repeated: {:?}",
+            target,
+            state.contract_ids
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Tests of the rate limit state, the windows of the callers are removed when
+// their calls expire, even if the callers don't call again
+use sails_rs::prelude::*;
+use app::states::{
+    proxy_route::ProxyRoute,
+    rate_limit_state::{
+        RateLimit,
+        RateLimitState
+    }
+};
+
+const WINDOW_BLOCKS: u32 = 10;
+
+fn setup() -> RateLimitState {
+    let mut state = RateLimitState::default();
+    state.set_limit(ProxyRoute::CallPing, RateLimit {
+        max_calls: 2,
+        window_blocks: WINDOW_BLOCKS
+    });
+
+    state
+}
+
+#[test]
+fn windows_of_callers_that_dont_call_again_are_removed() {
+    let mut state = setup();
+
+    for caller in 0..100u64 {
+        assert_eq!(state.check_and_record(caller.into(), ProxyRoute::CallPing, 1), Ok(()));
+    }
+    assert_eq!(state.calls.len(), 100);
+
+    // The windows are kept until their calls leave them
+    assert_eq!(state.check_and_record(100u64.into(), ProxyRoute::CallPing, WINDOW_BLOCKS), Ok(()));
+    assert_eq!(state.calls.len(), 101);
+
+    // A call of another caller removes all the expired windows
+    assert_eq!(state.check_and_record(101u64.into(), ProxyRoute::CallPing, WINDOW_BLOCKS + 1), Ok(()));
+    assert_eq!(state.calls.len(), 2);
+    assert!(!state.calls.contains_key(&(ActorId::from(0u64), ProxyRoute::CallPing)));
+}
+
+#[test]
+fn windows_with_calls_left_are_kept() {
+    let mut state = setup();
+    let caller = ActorId::from(1u64);
+
+    assert_eq!(state.check_and_record(caller, ProxyRoute::CallPing, 1), Ok(()));
+    assert_eq!(state.check_and_record(caller, ProxyRoute::CallPing, 5), Ok(()));
+    assert_eq!(state.check_and_record(caller, ProxyRoute::CallPing, 6), Err(WINDOW_BLOCKS + 1));
+
+    // Only the first call left the window, so the caller is still limited
+    state.check_and_record(2u64.into(), ProxyRoute::CallPong, WINDOW_BLOCKS + 1).unwrap();
+    assert_eq!(state.calls[&(caller, ProxyRoute::CallPing)], vec![5]);
+
+    // Once the last call leaves the window, the window is removed
+    state.check_and_record(2u64.into(), ProxyRoute::CallPong, WINDOW_BLOCKS + 5).unwrap();
+    assert!(state.calls.is_empty());
+}
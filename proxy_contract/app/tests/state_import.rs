@@ -0,0 +1,144 @@
+// Tests of the ImportState command of the ProxyService, the snapshots are
+// validated before they are restored and the import is locked after the
+// first command that changes the state of the proxy
+use sails_rs::{
+    prelude::*,
+    cell::RefCell
+};
+use sha2::{
+    Digest,
+    Sha256
+};
+use app::{
+    mocks::syscalls,
+    services::proxy_service::{
+        ProxyErrors,
+        ProxyEvent,
+        ProxyService
+    },
+    states::{
+        proxy_route::ProxyTarget,
+        proxy_state::ProxyState,
+        snapshot_state::{
+            ProxyStateExport,
+            ProxyStateSnapshot,
+            PROXY_STATE_SCHEMA_VERSION
+        }
+    }
+};
+
+const ADMIN: u64 = 1;
+const NEW_ADMIN: u64 = 2;
+const PING_CONTRACT: u64 = 10;
+const TRAFFIC_LIGHT_CONTRACT: u64 = 11;
+
+fn setup() -> RefCell<ProxyState> {
+    syscalls::reset();
+    syscalls::set_source(ADMIN.into());
+
+    RefCell::new(ProxyState::new(ADMIN.into(), Vec::new()))
+}
+
+fn snapshot(admins: Vec<u64>, contract_ids: Vec<(ProxyTarget, u64)>) -> ProxyStateSnapshot {
+    ProxyStateSnapshot {
+        admins: admins
+            .into_iter()
+            .map(ActorId::from)
+            .collect(),
+        contract_ids: contract_ids
+            .into_iter()
+            .map(|(target, id)| (target, id.into()))
+            .collect(),
+        on_behalf_of: Vec::new(),
+        rate_limits: Vec::new(),
+        rate_limit_exempt: Vec::new(),
+        route_fees: Vec::new(),
+        idempotency_ttl_blocks: 0
+    }
+}
+
+fn import(state: &RefCell<ProxyState>, export: ProxyStateExport) -> ProxyEvent {
+    ProxyService::new(state.borrow_mut()).import_state(export)
+}
+
+#[test]
+fn snapshot_is_imported_once() {
+    let state = setup();
+    let snapshot = snapshot(vec![NEW_ADMIN], vec![(ProxyTarget::PingContract, PING_CONTRACT)]);
+
+    let event = import(&state, ProxyStateExport::new(&snapshot));
+    assert_eq!(event, ProxyEvent::StateImported);
+    assert_eq!(state.borrow().admins, vec![ActorId::from(NEW_ADMIN), ActorId::from(ADMIN)]);
+    assert_eq!(state.borrow().contract_ids, vec![(ProxyTarget::PingContract, ActorId::from(PING_CONTRACT))]);
+
+    let event = import(&state, ProxyStateExport::new(&snapshot));
+    assert_eq!(event, ProxyEvent::Error(ProxyErrors::StateImportLocked));
+}
+
+// The import is locked by any command that changes the state, not only by the
+// proxied calls (a ResetMetrics didn't lock it before)
+#[test]
+fn import_is_locked_after_a_command() {
+    let state = setup();
+
+    let event = ProxyService::new(state.borrow_mut()).reset_metrics();
+    assert_eq!(event, ProxyEvent::MetricsReset);
+
+    let event = import(&state, ProxyStateExport::new(&snapshot(vec![NEW_ADMIN], Vec::new())));
+    assert_eq!(event, ProxyEvent::Error(ProxyErrors::StateImportLocked));
+    assert_eq!(state.borrow().admins, vec![ActorId::from(ADMIN)]);
+}
+
+// The commands that fail don't change the state, so they don't lock the import
+#[test]
+fn failed_commands_do_not_lock_the_import() {
+    let state = setup();
+
+    syscalls::set_source(NEW_ADMIN.into());
+    let event = ProxyService::new(state.borrow_mut()).reset_metrics();
+    assert_eq!(event, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanResetMetrics));
+
+    syscalls::set_source(ADMIN.into());
+    let event = import(&state, ProxyStateExport::new(&snapshot(vec![NEW_ADMIN], Vec::new())));
+    assert_eq!(event, ProxyEvent::StateImported);
+}
+
+#[test]
+fn imports_with_repeated_admins_or_targets_are_rejected() {
+    let state = setup();
+
+    for snapshot in [
+        snapshot(vec![NEW_ADMIN, NEW_ADMIN], Vec::new()),
+        snapshot(vec![NEW_ADMIN], vec![
+            (ProxyTarget::PingContract, PING_CONTRACT),
+            (ProxyTarget::PingContract, TRAFFIC_LIGHT_CONTRACT)
+        ])
+    ] {
+        let event = import(&state, ProxyStateExport::new(&snapshot));
+
+        assert_eq!(event, ProxyEvent::Error(ProxyErrors::InvalidStateData));
+        assert_eq!(state.borrow().admins, vec![ActorId::from(ADMIN)]);
+    }
+}
+
+#[test]
+fn imports_with_trailing_bytes_are_rejected() {
+    let state = setup();
+    let mut data = snapshot(vec![NEW_ADMIN], Vec::new()).encode();
+    data.push(0);
+
+    // The checksum is valid, so only the data is rejected
+    let mut hasher = Sha256::new();
+    hasher.update(PROXY_STATE_SCHEMA_VERSION.to_le_bytes());
+    hasher.update(&data);
+
+    let export = ProxyStateExport {
+        schema_version: PROXY_STATE_SCHEMA_VERSION,
+        checksum: hasher.finalize().into(),
+        data
+    };
+
+    let event = import(&state, export);
+    assert_eq!(event, ProxyEvent::Error(ProxyErrors::InvalidStateData));
+    assert_eq!(state.borrow().admins, vec![ActorId::from(ADMIN)]);
+}
@@ -74,6 +74,77 @@ pub mod app_factory {
         }
     }
 }
+pub struct Credits<R> {
+    remoting: R,
+}
+impl<R> Credits<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Credits for Credits<R> {
+    type Args = R::Args;
+    fn deposit(&mut self) -> impl Call<Output = ProxyCreditsEvent, Args = R::Args> {
+        RemotingAction::<_, credits::io::Deposit>::new(self.remoting.clone(), ())
+    }
+    fn withdraw(&mut self, amount: u128) -> impl Call<Output = ProxyCreditsEvent, Args = R::Args> {
+        RemotingAction::<_, credits::io::Withdraw>::new(self.remoting.clone(), amount)
+    }
+    fn balance(&self, account: ActorId) -> impl Query<Output = ProxyCreditsEvent, Args = R::Args> {
+        RemotingAction::<_, credits::io::Balance>::new(self.remoting.clone(), account)
+    }
+}
+
+pub mod credits {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Deposit(());
+        impl Deposit {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Deposit as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Deposit {
+            const ROUTE: &'static [u8] = &[
+                28, 67, 114, 101, 100, 105, 116, 115, 28, 68, 101, 112, 111, 115, 105, 116,
+            ];
+            type Params = ();
+            type Reply = super::ProxyCreditsEvent;
+        }
+        pub struct Withdraw(());
+        impl Withdraw {
+            #[allow(dead_code)]
+            pub fn encode_call(amount: u128) -> Vec<u8> {
+                <Withdraw as ActionIo>::encode_call(&amount)
+            }
+        }
+        impl ActionIo for Withdraw {
+            const ROUTE: &'static [u8] = &[
+                28, 67, 114, 101, 100, 105, 116, 115, 32, 87, 105, 116, 104, 100, 114, 97, 119,
+            ];
+            type Params = u128;
+            type Reply = super::ProxyCreditsEvent;
+        }
+        pub struct Balance(());
+        impl Balance {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <Balance as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for Balance {
+            const ROUTE: &'static [u8] = &[
+                28, 67, 114, 101, 100, 105, 116, 115, 28, 66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyCreditsEvent;
+        }
+    }
+}
 pub struct PingCaller<R> {
     remoting: R,
 }
@@ -87,12 +158,54 @@ impl<R: Remoting + Clone> traits::PingCaller for PingCaller<R> {
     fn call_ping(&mut self) -> impl Call<Output = ProxyPingCallerEvent, Args = R::Args> {
         RemotingAction::<_, ping_caller::io::CallPing>::new(self.remoting.clone(), ())
     }
+    fn call_ping_idempotent(
+        &mut self,
+        idempotency_key: H256,
+    ) -> impl Call<Output = ProxyPingCallerEvent, Args = R::Args> {
+        RemotingAction::<_, ping_caller::io::CallPingIdempotent>::new(
+            self.remoting.clone(),
+            idempotency_key,
+        )
+    }
     fn call_pong(&mut self) -> impl Call<Output = ProxyPingCallerEvent, Args = R::Args> {
         RemotingAction::<_, ping_caller::io::CallPong>::new(self.remoting.clone(), ())
     }
+    fn call_pong_idempotent(
+        &mut self,
+        idempotency_key: H256,
+    ) -> impl Call<Output = ProxyPingCallerEvent, Args = R::Args> {
+        RemotingAction::<_, ping_caller::io::CallPongIdempotent>::new(
+            self.remoting.clone(),
+            idempotency_key,
+        )
+    }
     fn call_all_calls(&self) -> impl Query<Output = ProxyPingCallerEvent, Args = R::Args> {
         RemotingAction::<_, ping_caller::io::CallAllCalls>::new(self.remoting.clone(), ())
     }
+    fn call_calls_by_actor(
+        &self,
+        actor: ActorId,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = ProxyPingCallerEvent, Args = R::Args> {
+        RemotingAction::<_, ping_caller::io::CallCallsByActor>::new(
+            self.remoting.clone(),
+            (actor, offset, limit),
+        )
+    }
+    fn call_calls_count(&self) -> impl Query<Output = ProxyPingCallerEvent, Args = R::Args> {
+        RemotingAction::<_, ping_caller::io::CallCallsCount>::new(self.remoting.clone(), ())
+    }
+    fn call_calls_page(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = ProxyPingCallerEvent, Args = R::Args> {
+        RemotingAction::<_, ping_caller::io::CallCallsPage>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
     fn call_last_who_call(&self) -> impl Query<Output = ProxyPingCallerEvent, Args = R::Args> {
         RemotingAction::<_, ping_caller::io::CallLastWhoCall>::new(self.remoting.clone(), ())
     }
@@ -119,6 +232,21 @@ pub mod ping_caller {
             type Params = ();
             type Reply = super::ProxyPingCallerEvent;
         }
+        pub struct CallPingIdempotent(());
+        impl CallPingIdempotent {
+            #[allow(dead_code)]
+            pub fn encode_call(idempotency_key: H256) -> Vec<u8> {
+                <CallPingIdempotent as ActionIo>::encode_call(&idempotency_key)
+            }
+        }
+        impl ActionIo for CallPingIdempotent {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 72, 67, 97, 108, 108, 80, 105,
+                110, 103, 73, 100, 101, 109, 112, 111, 116, 101, 110, 116,
+            ];
+            type Params = H256;
+            type Reply = super::ProxyPingCallerEvent;
+        }
         pub struct CallPong(());
         impl CallPong {
             #[allow(dead_code)]
@@ -134,137 +262,785 @@ pub mod ping_caller {
             type Params = ();
             type Reply = super::ProxyPingCallerEvent;
         }
+        pub struct CallPongIdempotent(());
+        impl CallPongIdempotent {
+            #[allow(dead_code)]
+            pub fn encode_call(idempotency_key: H256) -> Vec<u8> {
+                <CallPongIdempotent as ActionIo>::encode_call(&idempotency_key)
+            }
+        }
+        impl ActionIo for CallPongIdempotent {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 72, 67, 97, 108, 108, 80, 111,
+                110, 103, 73, 100, 101, 109, 112, 111, 116, 101, 110, 116,
+            ];
+            type Params = H256;
+            type Reply = super::ProxyPingCallerEvent;
+        }
         pub struct CallAllCalls(());
         impl CallAllCalls {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <CallAllCalls as ActionIo>::encode_call(&())
+            pub fn encode_call() -> Vec<u8> {
+                <CallAllCalls as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CallAllCalls {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 48, 67, 97, 108, 108, 65, 108,
+                108, 67, 97, 108, 108, 115,
+            ];
+            type Params = ();
+            type Reply = super::ProxyPingCallerEvent;
+        }
+        pub struct CallCallsByActor(());
+        impl CallCallsByActor {
+            #[allow(dead_code)]
+            pub fn encode_call(actor: ActorId, offset: u64, limit: u32) -> Vec<u8> {
+                <CallCallsByActor as ActionIo>::encode_call(&(actor, offset, limit))
+            }
+        }
+        impl ActionIo for CallCallsByActor {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 64, 67, 97, 108, 108, 67, 97,
+                108, 108, 115, 66, 121, 65, 99, 116, 111, 114,
+            ];
+            type Params = (ActorId, u64, u32);
+            type Reply = super::ProxyPingCallerEvent;
+        }
+        pub struct CallCallsCount(());
+        impl CallCallsCount {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CallCallsCount as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CallCallsCount {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 56, 67, 97, 108, 108, 67, 97,
+                108, 108, 115, 67, 111, 117, 110, 116,
+            ];
+            type Params = ();
+            type Reply = super::ProxyPingCallerEvent;
+        }
+        pub struct CallCallsPage(());
+        impl CallCallsPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u64, limit: u32) -> Vec<u8> {
+                <CallCallsPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for CallCallsPage {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 52, 67, 97, 108, 108, 67, 97,
+                108, 108, 115, 80, 97, 103, 101,
+            ];
+            type Params = (u64, u32);
+            type Reply = super::ProxyPingCallerEvent;
+        }
+        pub struct CallLastWhoCall(());
+        impl CallLastWhoCall {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CallLastWhoCall as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CallLastWhoCall {
+            const ROUTE: &'static [u8] = &[
+                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 60, 67, 97, 108, 108, 76, 97,
+                115, 116, 87, 104, 111, 67, 97, 108, 108,
+            ];
+            type Params = ();
+            type Reply = super::ProxyPingCallerEvent;
+        }
+    }
+}
+pub struct Proxy<R> {
+    remoting: R,
+}
+impl<R> Proxy<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Proxy for Proxy<R> {
+    type Args = R::Args;
+    fn add_admin(&mut self, new_admin: ActorId) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::AddAdmin>::new(self.remoting.clone(), new_admin)
+    }
+    fn add_rate_limit_exempt(
+        &mut self,
+        address: ActorId,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::AddRateLimitExempt>::new(self.remoting.clone(), address)
+    }
+    fn change_contract_id(
+        &mut self,
+        target: ProxyTarget,
+        contract_id: ActorId,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ChangeContractId>::new(
+            self.remoting.clone(),
+            (target, contract_id),
+        )
+    }
+    fn change_ping_contract_id(
+        &mut self,
+        contract_id: ActorId,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ChangePingContractId>::new(
+            self.remoting.clone(),
+            contract_id,
+        )
+    }
+    fn change_traffic_light_contract_id(
+        &mut self,
+        contract_id: ActorId,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ChangeTrafficLightContractId>::new(
+            self.remoting.clone(),
+            contract_id,
+        )
+    }
+    fn import_state(
+        &mut self,
+        export: ProxyStateExport,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ImportState>::new(self.remoting.clone(), export)
+    }
+    fn remove_rate_limit(
+        &mut self,
+        route: ProxyRoute,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::RemoveRateLimit>::new(self.remoting.clone(), route)
+    }
+    fn remove_rate_limit_exempt(
+        &mut self,
+        address: ActorId,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::RemoveRateLimitExempt>::new(self.remoting.clone(), address)
+    }
+    fn reset_metrics(&mut self) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ResetMetrics>::new(self.remoting.clone(), ())
+    }
+    fn set_idempotency_ttl(
+        &mut self,
+        ttl_blocks: u32,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::SetIdempotencyTtl>::new(self.remoting.clone(), ttl_blocks)
+    }
+    fn set_on_behalf_of(
+        &mut self,
+        target: ProxyTarget,
+        enabled: bool,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::SetOnBehalfOf>::new(self.remoting.clone(), (target, enabled))
+    }
+    fn set_rate_limit(
+        &mut self,
+        route: ProxyRoute,
+        max_calls: u32,
+        window_blocks: u32,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::SetRateLimit>::new(
+            self.remoting.clone(),
+            (route, max_calls, window_blocks),
+        )
+    }
+    fn set_route_fee(
+        &mut self,
+        route: ProxyRoute,
+        fee: u128,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::SetRouteFee>::new(self.remoting.clone(), (route, fee))
+    }
+    fn withdraw_fees(
+        &mut self,
+        to: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::WithdrawFees>::new(self.remoting.clone(), (to, amount))
+    }
+    fn contract_ids(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ContractIds>::new(self.remoting.clone(), ())
+    }
+    fn contracts_id(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ContractsId>::new(self.remoting.clone(), ())
+    }
+    fn export_state(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::ExportState>::new(self.remoting.clone(), ())
+    }
+    fn fees(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::Fees>::new(self.remoting.clone(), ())
+    }
+    fn idempotency_ttl(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::IdempotencyTtl>::new(self.remoting.clone(), ())
+    }
+    fn info(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::Info>::new(self.remoting.clone(), ())
+    }
+    fn metrics(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::Metrics>::new(self.remoting.clone(), ())
+    }
+    fn on_behalf_of(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::OnBehalfOf>::new(self.remoting.clone(), ())
+    }
+    fn rate_limits(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
+        RemotingAction::<_, proxy::io::RateLimits>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod proxy {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AddAdmin(());
+        impl AddAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
+                <AddAdmin as ActionIo>::encode_call(&new_admin)
+            }
+        }
+        impl ActionIo for AddAdmin {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 32, 65, 100, 100, 65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct AddRateLimitExempt(());
+        impl AddRateLimitExempt {
+            #[allow(dead_code)]
+            pub fn encode_call(address: ActorId) -> Vec<u8> {
+                <AddRateLimitExempt as ActionIo>::encode_call(&address)
+            }
+        }
+        impl ActionIo for AddRateLimitExempt {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 72, 65, 100, 100, 82, 97, 116, 101, 76, 105, 109, 105,
+                116, 69, 120, 101, 109, 112, 116,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ChangeContractId(());
+        impl ChangeContractId {
+            #[allow(dead_code)]
+            pub fn encode_call(target: super::ProxyTarget, contract_id: ActorId) -> Vec<u8> {
+                <ChangeContractId as ActionIo>::encode_call(&(target, contract_id))
+            }
+        }
+        impl ActionIo for ChangeContractId {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 64, 67, 104, 97, 110, 103, 101, 67, 111, 110, 116, 114,
+                97, 99, 116, 73, 100,
+            ];
+            type Params = (super::ProxyTarget, ActorId);
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ChangePingContractId(());
+        impl ChangePingContractId {
+            #[allow(dead_code)]
+            pub fn encode_call(contract_id: ActorId) -> Vec<u8> {
+                <ChangePingContractId as ActionIo>::encode_call(&contract_id)
+            }
+        }
+        impl ActionIo for ChangePingContractId {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 80, 67, 104, 97, 110, 103, 101, 80, 105, 110, 103, 67,
+                111, 110, 116, 114, 97, 99, 116, 73, 100,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ChangeTrafficLightContractId(());
+        impl ChangeTrafficLightContractId {
+            #[allow(dead_code)]
+            pub fn encode_call(contract_id: ActorId) -> Vec<u8> {
+                <ChangeTrafficLightContractId as ActionIo>::encode_call(&contract_id)
+            }
+        }
+        impl ActionIo for ChangeTrafficLightContractId {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 112, 67, 104, 97, 110, 103, 101, 84, 114, 97, 102, 102,
+                105, 99, 76, 105, 103, 104, 116, 67, 111, 110, 116, 114, 97, 99, 116, 73, 100,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ImportState(());
+        impl ImportState {
+            #[allow(dead_code)]
+            pub fn encode_call(export: super::ProxyStateExport) -> Vec<u8> {
+                <ImportState as ActionIo>::encode_call(&export)
+            }
+        }
+        impl ActionIo for ImportState {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 44, 73, 109, 112, 111, 114, 116, 83, 116, 97, 116, 101,
+            ];
+            type Params = super::ProxyStateExport;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct RemoveRateLimit(());
+        impl RemoveRateLimit {
+            #[allow(dead_code)]
+            pub fn encode_call(route: super::ProxyRoute) -> Vec<u8> {
+                <RemoveRateLimit as ActionIo>::encode_call(&route)
+            }
+        }
+        impl ActionIo for RemoveRateLimit {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 60, 82, 101, 109, 111, 118, 101, 82, 97, 116, 101, 76,
+                105, 109, 105, 116,
+            ];
+            type Params = super::ProxyRoute;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct RemoveRateLimitExempt(());
+        impl RemoveRateLimitExempt {
+            #[allow(dead_code)]
+            pub fn encode_call(address: ActorId) -> Vec<u8> {
+                <RemoveRateLimitExempt as ActionIo>::encode_call(&address)
+            }
+        }
+        impl ActionIo for RemoveRateLimitExempt {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 84, 82, 101, 109, 111, 118, 101, 82, 97, 116, 101, 76,
+                105, 109, 105, 116, 69, 120, 101, 109, 112, 116,
+            ];
+            type Params = ActorId;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ResetMetrics(());
+        impl ResetMetrics {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ResetMetrics as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ResetMetrics {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 48, 82, 101, 115, 101, 116, 77, 101, 116, 114, 105, 99,
+                115,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct SetIdempotencyTtl(());
+        impl SetIdempotencyTtl {
+            #[allow(dead_code)]
+            pub fn encode_call(ttl_blocks: u32) -> Vec<u8> {
+                <SetIdempotencyTtl as ActionIo>::encode_call(&ttl_blocks)
+            }
+        }
+        impl ActionIo for SetIdempotencyTtl {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 68, 83, 101, 116, 73, 100, 101, 109, 112, 111, 116,
+                101, 110, 99, 121, 84, 116, 108,
+            ];
+            type Params = u32;
+            type Reply = super::ProxyEvent;
+        }
+        pub struct SetOnBehalfOf(());
+        impl SetOnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call(target: super::ProxyTarget, enabled: bool) -> Vec<u8> {
+                <SetOnBehalfOf as ActionIo>::encode_call(&(target, enabled))
+            }
+        }
+        impl ActionIo for SetOnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 52, 83, 101, 116, 79, 110, 66, 101, 104, 97, 108, 102,
+                79, 102,
+            ];
+            type Params = (super::ProxyTarget, bool);
+            type Reply = super::ProxyEvent;
+        }
+        pub struct SetRateLimit(());
+        impl SetRateLimit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                route: super::ProxyRoute,
+                max_calls: u32,
+                window_blocks: u32,
+            ) -> Vec<u8> {
+                <SetRateLimit as ActionIo>::encode_call(&(route, max_calls, window_blocks))
+            }
+        }
+        impl ActionIo for SetRateLimit {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 48, 83, 101, 116, 82, 97, 116, 101, 76, 105, 109, 105,
+                116,
+            ];
+            type Params = (super::ProxyRoute, u32, u32);
+            type Reply = super::ProxyEvent;
+        }
+        pub struct SetRouteFee(());
+        impl SetRouteFee {
+            #[allow(dead_code)]
+            pub fn encode_call(route: super::ProxyRoute, fee: u128) -> Vec<u8> {
+                <SetRouteFee as ActionIo>::encode_call(&(route, fee))
+            }
+        }
+        impl ActionIo for SetRouteFee {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 44, 83, 101, 116, 82, 111, 117, 116, 101, 70, 101, 101,
+            ];
+            type Params = (super::ProxyRoute, u128);
+            type Reply = super::ProxyEvent;
+        }
+        pub struct WithdrawFees(());
+        impl WithdrawFees {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawFees as ActionIo>::encode_call(&(to, amount))
+            }
+        }
+        impl ActionIo for WithdrawFees {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 48, 87, 105, 116, 104, 100, 114, 97, 119, 70, 101, 101,
+                115,
+            ];
+            type Params = (ActorId, u128);
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ContractIds(());
+        impl ContractIds {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ContractIds as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ContractIds {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 44, 67, 111, 110, 116, 114, 97, 99, 116, 73, 100, 115,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ContractsId(());
+        impl ContractsId {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ContractsId as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ContractsId {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 44, 67, 111, 110, 116, 114, 97, 99, 116, 115, 73, 100,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct ExportState(());
+        impl ExportState {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ExportState as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ExportState {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 44, 69, 120, 112, 111, 114, 116, 83, 116, 97, 116, 101,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct Fees(());
+        impl Fees {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Fees as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Fees {
+            const ROUTE: &'static [u8] = &[20, 80, 114, 111, 120, 121, 16, 70, 101, 101, 115];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct IdempotencyTtl(());
+        impl IdempotencyTtl {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <IdempotencyTtl as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for IdempotencyTtl {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 56, 73, 100, 101, 109, 112, 111, 116, 101, 110, 99,
+                121, 84, 116, 108,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct Info(());
+        impl Info {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Info as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Info {
+            const ROUTE: &'static [u8] = &[20, 80, 114, 111, 120, 121, 16, 73, 110, 102, 111];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct Metrics(());
+        impl Metrics {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Metrics as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Metrics {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 28, 77, 101, 116, 114, 105, 99, 115,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct OnBehalfOf(());
+        impl OnBehalfOf {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <OnBehalfOf as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for OnBehalfOf {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 40, 79, 110, 66, 101, 104, 97, 108, 102, 79, 102,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+        pub struct RateLimits(());
+        impl RateLimits {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <RateLimits as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for RateLimits {
+            const ROUTE: &'static [u8] = &[
+                20, 80, 114, 111, 120, 121, 40, 82, 97, 116, 101, 76, 105, 109, 105, 116, 115,
+            ];
+            type Params = ();
+            type Reply = super::ProxyEvent;
+        }
+    }
+}
+pub struct Relayer<R> {
+    remoting: R,
+}
+impl<R> Relayer<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Relayer for Relayer<R> {
+    type Args = R::Args;
+    fn relay_call(
+        &mut self,
+        public_key: [u8; 32],
+        operation: Vec<u8>,
+        nonce: u64,
+        deadline: u32,
+        signature: RelaySignature,
+    ) -> impl Call<Output = ProxyRelayerEvent, Args = R::Args> {
+        RemotingAction::<_, relayer::io::RelayCall>::new(
+            self.remoting.clone(),
+            (public_key, operation, nonce, deadline, signature),
+        )
+    }
+    fn nonce(&self, user: ActorId) -> impl Query<Output = ProxyRelayerEvent, Args = R::Args> {
+        RemotingAction::<_, relayer::io::Nonce>::new(self.remoting.clone(), user)
+    }
+}
+
+pub mod relayer {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct RelayCall(());
+        impl RelayCall {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                public_key: [u8; 32],
+                operation: Vec<u8>,
+                nonce: u64,
+                deadline: u32,
+                signature: super::RelaySignature,
+            ) -> Vec<u8> {
+                <RelayCall as ActionIo>::encode_call(&(
+                    public_key, operation, nonce, deadline, signature,
+                ))
             }
         }
-        impl ActionIo for CallAllCalls {
+        impl ActionIo for RelayCall {
             const ROUTE: &'static [u8] = &[
-                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 48, 67, 97, 108, 108, 65, 108,
-                108, 67, 97, 108, 108, 115,
+                28, 82, 101, 108, 97, 121, 101, 114, 36, 82, 101, 108, 97, 121, 67, 97, 108, 108,
             ];
-            type Params = ();
-            type Reply = super::ProxyPingCallerEvent;
+            type Params = ([u8; 32], Vec<u8>, u64, u32, super::RelaySignature);
+            type Reply = super::ProxyRelayerEvent;
         }
-        pub struct CallLastWhoCall(());
-        impl CallLastWhoCall {
+        pub struct Nonce(());
+        impl Nonce {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <CallLastWhoCall as ActionIo>::encode_call(&())
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <Nonce as ActionIo>::encode_call(&user)
             }
         }
-        impl ActionIo for CallLastWhoCall {
+        impl ActionIo for Nonce {
             const ROUTE: &'static [u8] = &[
-                40, 80, 105, 110, 103, 67, 97, 108, 108, 101, 114, 60, 67, 97, 108, 108, 76, 97,
-                115, 116, 87, 104, 111, 67, 97, 108, 108,
+                28, 82, 101, 108, 97, 121, 101, 114, 20, 78, 111, 110, 99, 101,
             ];
-            type Params = ();
-            type Reply = super::ProxyPingCallerEvent;
+            type Params = ActorId;
+            type Reply = super::ProxyRelayerEvent;
         }
     }
 }
-pub struct Proxy<R> {
+pub struct Sessions<R> {
     remoting: R,
 }
-impl<R> Proxy<R> {
+impl<R> Sessions<R> {
     pub fn new(remoting: R) -> Self {
         Self { remoting }
     }
 }
-impl<R: Remoting + Clone> traits::Proxy for Proxy<R> {
+impl<R: Remoting + Clone> traits::Sessions for Sessions<R> {
     type Args = R::Args;
-    fn add_admin(&mut self, new_admin: ActorId) -> impl Call<Output = ProxyEvent, Args = R::Args> {
-        RemotingAction::<_, proxy::io::AddAdmin>::new(self.remoting.clone(), new_admin)
+    fn accept_session_key(
+        &mut self,
+        owner: ActorId,
+    ) -> impl Call<Output = ProxySessionsEvent, Args = R::Args> {
+        RemotingAction::<_, sessions::io::AcceptSessionKey>::new(self.remoting.clone(), owner)
     }
-    fn change_ping_contract_id(
+    fn register_session_key(
         &mut self,
-        contract_id: ActorId,
-    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
-        RemotingAction::<_, proxy::io::ChangePingContractId>::new(
+        session_key: ActorId,
+        scope: Vec<ProxyRoute>,
+        expires_at: u32,
+        max_calls: u32,
+    ) -> impl Call<Output = ProxySessionsEvent, Args = R::Args> {
+        RemotingAction::<_, sessions::io::RegisterSessionKey>::new(
             self.remoting.clone(),
-            contract_id,
+            (session_key, scope, expires_at, max_calls),
         )
     }
-    fn change_traffic_light_contract_id(
+    fn revoke_session_key(
         &mut self,
-        contract_id: ActorId,
-    ) -> impl Call<Output = ProxyEvent, Args = R::Args> {
-        RemotingAction::<_, proxy::io::ChangeTrafficLightContractId>::new(
-            self.remoting.clone(),
-            contract_id,
-        )
+        session_key: ActorId,
+    ) -> impl Call<Output = ProxySessionsEvent, Args = R::Args> {
+        RemotingAction::<_, sessions::io::RevokeSessionKey>::new(self.remoting.clone(), session_key)
     }
-    fn contracts_id(&self) -> impl Query<Output = ProxyEvent, Args = R::Args> {
-        RemotingAction::<_, proxy::io::ContractsId>::new(self.remoting.clone(), ())
+    fn session_key(
+        &self,
+        session_key: ActorId,
+    ) -> impl Query<Output = ProxySessionsEvent, Args = R::Args> {
+        RemotingAction::<_, sessions::io::SessionKey>::new(self.remoting.clone(), session_key)
+    }
+    fn session_keys(
+        &self,
+        owner: ActorId,
+    ) -> impl Query<Output = ProxySessionsEvent, Args = R::Args> {
+        RemotingAction::<_, sessions::io::SessionKeys>::new(self.remoting.clone(), owner)
     }
 }
 
-pub mod proxy {
+pub mod sessions {
     use super::*;
 
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
-        pub struct AddAdmin(());
-        impl AddAdmin {
+        pub struct AcceptSessionKey(());
+        impl AcceptSessionKey {
             #[allow(dead_code)]
-            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
-                <AddAdmin as ActionIo>::encode_call(&new_admin)
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <AcceptSessionKey as ActionIo>::encode_call(&owner)
             }
         }
-        impl ActionIo for AddAdmin {
+        impl ActionIo for AcceptSessionKey {
             const ROUTE: &'static [u8] = &[
-                20, 80, 114, 111, 120, 121, 32, 65, 100, 100, 65, 100, 109, 105, 110,
+                32, 83, 101, 115, 115, 105, 111, 110, 115, 64, 65, 99, 99, 101, 112, 116, 83, 101,
+                115, 115, 105, 111, 110, 75, 101, 121,
             ];
             type Params = ActorId;
-            type Reply = super::ProxyEvent;
+            type Reply = super::ProxySessionsEvent;
         }
-        pub struct ChangePingContractId(());
-        impl ChangePingContractId {
+        pub struct RegisterSessionKey(());
+        impl RegisterSessionKey {
             #[allow(dead_code)]
-            pub fn encode_call(contract_id: ActorId) -> Vec<u8> {
-                <ChangePingContractId as ActionIo>::encode_call(&contract_id)
+            pub fn encode_call(
+                session_key: ActorId,
+                scope: Vec<super::ProxyRoute>,
+                expires_at: u32,
+                max_calls: u32,
+            ) -> Vec<u8> {
+                <RegisterSessionKey as ActionIo>::encode_call(&(
+                    session_key,
+                    scope,
+                    expires_at,
+                    max_calls,
+                ))
             }
         }
-        impl ActionIo for ChangePingContractId {
+        impl ActionIo for RegisterSessionKey {
             const ROUTE: &'static [u8] = &[
-                20, 80, 114, 111, 120, 121, 80, 67, 104, 97, 110, 103, 101, 80, 105, 110, 103, 67,
-                111, 110, 116, 114, 97, 99, 116, 73, 100,
+                32, 83, 101, 115, 115, 105, 111, 110, 115, 72, 82, 101, 103, 105, 115, 116, 101,
+                114, 83, 101, 115, 115, 105, 111, 110, 75, 101, 121,
+            ];
+            type Params = (ActorId, Vec<super::ProxyRoute>, u32, u32);
+            type Reply = super::ProxySessionsEvent;
+        }
+        pub struct RevokeSessionKey(());
+        impl RevokeSessionKey {
+            #[allow(dead_code)]
+            pub fn encode_call(session_key: ActorId) -> Vec<u8> {
+                <RevokeSessionKey as ActionIo>::encode_call(&session_key)
+            }
+        }
+        impl ActionIo for RevokeSessionKey {
+            const ROUTE: &'static [u8] = &[
+                32, 83, 101, 115, 115, 105, 111, 110, 115, 64, 82, 101, 118, 111, 107, 101, 83,
+                101, 115, 115, 105, 111, 110, 75, 101, 121,
             ];
             type Params = ActorId;
-            type Reply = super::ProxyEvent;
+            type Reply = super::ProxySessionsEvent;
         }
-        pub struct ChangeTrafficLightContractId(());
-        impl ChangeTrafficLightContractId {
+        pub struct SessionKey(());
+        impl SessionKey {
             #[allow(dead_code)]
-            pub fn encode_call(contract_id: ActorId) -> Vec<u8> {
-                <ChangeTrafficLightContractId as ActionIo>::encode_call(&contract_id)
+            pub fn encode_call(session_key: ActorId) -> Vec<u8> {
+                <SessionKey as ActionIo>::encode_call(&session_key)
             }
         }
-        impl ActionIo for ChangeTrafficLightContractId {
+        impl ActionIo for SessionKey {
             const ROUTE: &'static [u8] = &[
-                20, 80, 114, 111, 120, 121, 112, 67, 104, 97, 110, 103, 101, 84, 114, 97, 102, 102,
-                105, 99, 76, 105, 103, 104, 116, 67, 111, 110, 116, 114, 97, 99, 116, 73, 100,
+                32, 83, 101, 115, 115, 105, 111, 110, 115, 40, 83, 101, 115, 115, 105, 111, 110,
+                75, 101, 121,
             ];
             type Params = ActorId;
-            type Reply = super::ProxyEvent;
+            type Reply = super::ProxySessionsEvent;
         }
-        pub struct ContractsId(());
-        impl ContractsId {
+        pub struct SessionKeys(());
+        impl SessionKeys {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <ContractsId as ActionIo>::encode_call(&())
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <SessionKeys as ActionIo>::encode_call(&owner)
             }
         }
-        impl ActionIo for ContractsId {
+        impl ActionIo for SessionKeys {
             const ROUTE: &'static [u8] = &[
-                20, 80, 114, 111, 120, 121, 44, 67, 111, 110, 116, 114, 97, 99, 116, 115, 73, 100,
+                32, 83, 101, 115, 115, 105, 111, 110, 115, 44, 83, 101, 115, 115, 105, 111, 110,
+                75, 101, 121, 115,
             ];
-            type Params = ();
-            type Reply = super::ProxyEvent;
+            type Params = ActorId;
+            type Reply = super::ProxySessionsEvent;
         }
     }
 }
@@ -281,12 +1057,39 @@ impl<R: Remoting + Clone> traits::TrafficLightCaller for TrafficLightCaller<R> {
     fn call_green(&mut self) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light_caller::io::CallGreen>::new(self.remoting.clone(), ())
     }
+    fn call_green_idempotent(
+        &mut self,
+        idempotency_key: H256,
+    ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light_caller::io::CallGreenIdempotent>::new(
+            self.remoting.clone(),
+            idempotency_key,
+        )
+    }
     fn call_red(&mut self) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light_caller::io::CallRed>::new(self.remoting.clone(), ())
     }
+    fn call_red_idempotent(
+        &mut self,
+        idempotency_key: H256,
+    ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light_caller::io::CallRedIdempotent>::new(
+            self.remoting.clone(),
+            idempotency_key,
+        )
+    }
     fn call_yellow(&mut self) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
         RemotingAction::<_, traffic_light_caller::io::CallYellow>::new(self.remoting.clone(), ())
     }
+    fn call_yellow_idempotent(
+        &mut self,
+        idempotency_key: H256,
+    ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
+        RemotingAction::<_, traffic_light_caller::io::CallYellowIdempotent>::new(
+            self.remoting.clone(),
+            idempotency_key,
+        )
+    }
     fn call_traffic_light_state(
         &self,
     ) -> impl Query<Output = ProxyTrafficLightCallerEvent, Args = R::Args> {
@@ -318,6 +1121,22 @@ pub mod traffic_light_caller {
             type Params = ();
             type Reply = super::ProxyTrafficLightCallerEvent;
         }
+        pub struct CallGreenIdempotent(());
+        impl CallGreenIdempotent {
+            #[allow(dead_code)]
+            pub fn encode_call(idempotency_key: H256) -> Vec<u8> {
+                <CallGreenIdempotent as ActionIo>::encode_call(&idempotency_key)
+            }
+        }
+        impl ActionIo for CallGreenIdempotent {
+            const ROUTE: &'static [u8] = &[
+                72, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 67, 97, 108, 108, 101,
+                114, 76, 67, 97, 108, 108, 71, 114, 101, 101, 110, 73, 100, 101, 109, 112, 111,
+                116, 101, 110, 116,
+            ];
+            type Params = H256;
+            type Reply = super::ProxyTrafficLightCallerEvent;
+        }
         pub struct CallRed(());
         impl CallRed {
             #[allow(dead_code)]
@@ -333,6 +1152,22 @@ pub mod traffic_light_caller {
             type Params = ();
             type Reply = super::ProxyTrafficLightCallerEvent;
         }
+        pub struct CallRedIdempotent(());
+        impl CallRedIdempotent {
+            #[allow(dead_code)]
+            pub fn encode_call(idempotency_key: H256) -> Vec<u8> {
+                <CallRedIdempotent as ActionIo>::encode_call(&idempotency_key)
+            }
+        }
+        impl ActionIo for CallRedIdempotent {
+            const ROUTE: &'static [u8] = &[
+                72, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 67, 97, 108, 108, 101,
+                114, 68, 67, 97, 108, 108, 82, 101, 100, 73, 100, 101, 109, 112, 111, 116, 101,
+                110, 116,
+            ];
+            type Params = H256;
+            type Reply = super::ProxyTrafficLightCallerEvent;
+        }
         pub struct CallYellow(());
         impl CallYellow {
             #[allow(dead_code)]
@@ -348,6 +1183,22 @@ pub mod traffic_light_caller {
             type Params = ();
             type Reply = super::ProxyTrafficLightCallerEvent;
         }
+        pub struct CallYellowIdempotent(());
+        impl CallYellowIdempotent {
+            #[allow(dead_code)]
+            pub fn encode_call(idempotency_key: H256) -> Vec<u8> {
+                <CallYellowIdempotent as ActionIo>::encode_call(&idempotency_key)
+            }
+        }
+        impl ActionIo for CallYellowIdempotent {
+            const ROUTE: &'static [u8] = &[
+                72, 84, 114, 97, 102, 102, 105, 99, 76, 105, 103, 104, 116, 67, 97, 108, 108, 101,
+                114, 80, 67, 97, 108, 108, 89, 101, 108, 108, 111, 119, 73, 100, 101, 109, 112,
+                111, 116, 101, 110, 116,
+            ];
+            type Params = H256;
+            type Reply = super::ProxyTrafficLightCallerEvent;
+        }
         pub struct CallTrafficLightState(());
         impl CallTrafficLightState {
             #[allow(dead_code)]
@@ -369,12 +1220,32 @@ pub mod traffic_light_caller {
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyCreditsEvent {
+    Deposited(u128),
+    Withdrawn(u128),
+    Balance(u128),
+    Error(ProxyCreditsErrors),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyCreditsErrors {
+    NoValueAttached,
+    InsufficientCredit(u128),
+    TransferFailed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub enum ProxyPingCallerEvent {
     Test,
     PingContractStateAllCalls(Vec<(ActorId, PingEnum)>),
     PingContractStateLastWhoCall((ActorId, PingEnum)),
     PingContractResponse(PingEnum),
     Error(ProxyPingCallerError),
+    PingContractStateCallsPage(CallsPage),
+    PingContractStateCallsByActor(CallsPage),
+    PingContractStateCallsCount(u32),
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -389,6 +1260,36 @@ pub enum PingEnum {
 pub enum ProxyPingCallerError {
     PingContractIdNotSet,
     PingContractError(String),
+    RateLimitExceeded { retry_after_block: u32 },
+    InsufficientFee { required: u128, attached: u128 },
+    InsufficientCredit { required: u128, balance: u128 },
+    SessionKey(SessionKeyError),
+    IdempotencyKeyReused(ProxyRoute),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum SessionKeyError {
+    Expired(u32),
+    RouteNotInScope(ProxyRoute),
+    CallsExhausted(u32),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRoute {
+    CallPing,
+    CallPong,
+    CallGreen,
+    CallYellow,
+    CallRed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallsPage {
+    pub calls: Vec<(ActorId, PingEnum)>,
+    pub next_offset: Option<u64>,
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -401,6 +1302,25 @@ pub enum ProxyEvent {
     TrafficLightContractId(Option<ActorId>),
     ContractsId(ContractsId),
     Error(ProxyErrors),
+    ContractIdSet(ProxyTarget),
+    ContractIds(Vec<(ProxyTarget, ActorId)>),
+    Metrics(ProxyMetrics),
+    MetricsReset,
+    RateLimitSet(ProxyRoute),
+    RateLimitRemoved(ProxyRoute),
+    RateLimitExemptAdded(ActorId),
+    RateLimitExemptRemoved(ActorId),
+    RateLimits(RateLimitsConfig),
+    RouteFeeSet((ProxyRoute, u128)),
+    FeesWithdrawn((ActorId, u128)),
+    Fees(FeesInfo),
+    IdempotencyTtlSet(u32),
+    IdempotencyTtl(u32),
+    StateImported,
+    StateExport(ProxyStateExport),
+    Info(ProgramInfo),
+    OnBehalfOfSet((ProxyTarget, bool)),
+    OnBehalfOf(Vec<ProxyTarget>),
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -415,6 +1335,110 @@ pub struct ContractsId {
 pub enum ProxyErrors {
     OnlyAdminsCanChangeContactId,
     AdminExistsInContract(ActorId),
+    OnlyAdminsCanResetMetrics,
+    OnlyAdminsCanChangeRateLimits,
+    InvalidRateLimit,
+    RateLimitNotSet(ProxyRoute),
+    AddressIsAlreadyExempt(ActorId),
+    AddressIsNotExempt(ActorId),
+    OnlyAdminsCanManageFees,
+    InsufficientFeesToWithdraw(u128),
+    FeesTransferFailed,
+    OnlyAdminsCanChangeIdempotencyTtl,
+    OnlyAdminsCanImportState,
+    StateImportLocked,
+    IncompatibleSchemaVersion(u32),
+    InvalidStateChecksum,
+    InvalidStateData,
+    OnlyAdminsCanChangeOnBehalfOf,
+    TargetNotEnabled(ProxyTarget),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyTarget {
+    PingContract,
+    TrafficLightContract,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProxyMetrics {
+    pub targets: Vec<(ProxyTarget, CallMetrics)>,
+    pub routes: Vec<(ProxyRoute, CallMetrics)>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CallMetrics {
+    pub calls: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub last_call_block: Option<u32>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RateLimitsConfig {
+    pub limits: Vec<(ProxyRoute, RateLimit)>,
+    pub exempt: Vec<ActorId>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RateLimit {
+    pub max_calls: u32,
+    pub window_blocks: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeesInfo {
+    pub route_fees: Vec<(ProxyRoute, u128)>,
+    pub total_collected: u128,
+    pub total_withdrawn: u128,
+    pub balance: u128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProxyStateExport {
+    pub schema_version: u32,
+    pub checksum: [u8; 32],
+    pub data: Vec<u8>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramInfo {
+    pub version: String,
+    pub git_rev: String,
+    pub idl_hash: H256,
+    pub services: Vec<ServiceInfo>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub commands: Vec<String>,
+    pub queries: Vec<String>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RelaySignature {
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRelayerEvent {
+    PingCaller(ProxyPingCallerEvent),
+    TrafficLightCaller(ProxyTrafficLightCallerEvent),
+    Nonce(u64),
+    Error(ProxyRelayerErrors),
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -446,6 +1470,54 @@ pub struct IoTrafficLightState {
 pub enum ProxyTrafficLightCallerError {
     TrafficLightContractIdNotSet,
     TrafficLightContractError(String),
+    RateLimitExceeded { retry_after_block: u32 },
+    InsufficientFee { required: u128, attached: u128 },
+    InsufficientCredit { required: u128, balance: u128 },
+    SessionKey(SessionKeyError),
+    IdempotencyKeyReused(ProxyRoute),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxyRelayerErrors {
+    DeadlineExpired(u32),
+    InvalidOperation,
+    InvalidSignature,
+    InvalidNonce(u64),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxySessionsEvent {
+    SessionKeyRegistered(ActorId),
+    SessionKeyRevoked(ActorId),
+    SessionKey(Option<SessionKey>),
+    SessionKeys(Vec<(ActorId, SessionKey)>),
+    Error(ProxySessionsErrors),
+    SessionKeyAccepted(ActorId),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SessionKey {
+    pub owner: ActorId,
+    pub scope: Vec<ProxyRoute>,
+    pub expires_at: u32,
+    pub max_calls: u32,
+    pub calls: u32,
+    pub accepted: bool,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProxySessionsErrors {
+    InvalidSessionKey(ActorId),
+    SessionKeyExists(ActorId),
+    InvalidSessionParameters,
+    SessionKeyNotFound(ActorId),
+    OnlyOwnerOrSessionKeyCanRevoke,
+    SessionKeyNotProposed(ActorId),
+    SessionKeyAlreadyAccepted(ActorId),
 }
 
 pub mod traits {
@@ -463,12 +1535,46 @@ pub mod traits {
         ) -> impl Activation<Args = Self::Args>;
     }
 
+    #[allow(clippy::type_complexity)]
+    pub trait Credits {
+        type Args;
+        fn deposit(&mut self) -> impl Call<Output = ProxyCreditsEvent, Args = Self::Args>;
+        fn withdraw(
+            &mut self,
+            amount: u128,
+        ) -> impl Call<Output = ProxyCreditsEvent, Args = Self::Args>;
+        fn balance(
+            &self,
+            account: ActorId,
+        ) -> impl Query<Output = ProxyCreditsEvent, Args = Self::Args>;
+    }
+
     #[allow(clippy::type_complexity)]
     pub trait PingCaller {
         type Args;
         fn call_ping(&mut self) -> impl Call<Output = ProxyPingCallerEvent, Args = Self::Args>;
+        fn call_ping_idempotent(
+            &mut self,
+            idempotency_key: H256,
+        ) -> impl Call<Output = ProxyPingCallerEvent, Args = Self::Args>;
         fn call_pong(&mut self) -> impl Call<Output = ProxyPingCallerEvent, Args = Self::Args>;
+        fn call_pong_idempotent(
+            &mut self,
+            idempotency_key: H256,
+        ) -> impl Call<Output = ProxyPingCallerEvent, Args = Self::Args>;
         fn call_all_calls(&self) -> impl Query<Output = ProxyPingCallerEvent, Args = Self::Args>;
+        fn call_calls_by_actor(
+            &self,
+            actor: ActorId,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = ProxyPingCallerEvent, Args = Self::Args>;
+        fn call_calls_count(&self) -> impl Query<Output = ProxyPingCallerEvent, Args = Self::Args>;
+        fn call_calls_page(
+            &self,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = ProxyPingCallerEvent, Args = Self::Args>;
         fn call_last_who_call(
             &self,
         ) -> impl Query<Output = ProxyPingCallerEvent, Args = Self::Args>;
@@ -481,6 +1587,15 @@ pub mod traits {
             &mut self,
             new_admin: ActorId,
         ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn add_rate_limit_exempt(
+            &mut self,
+            address: ActorId,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn change_contract_id(
+            &mut self,
+            target: ProxyTarget,
+            contract_id: ActorId,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
         fn change_ping_contract_id(
             &mut self,
             contract_id: ActorId,
@@ -489,7 +1604,96 @@ pub mod traits {
             &mut self,
             contract_id: ActorId,
         ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn import_state(
+            &mut self,
+            export: ProxyStateExport,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn remove_rate_limit(
+            &mut self,
+            route: ProxyRoute,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn remove_rate_limit_exempt(
+            &mut self,
+            address: ActorId,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn reset_metrics(&mut self) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn set_idempotency_ttl(
+            &mut self,
+            ttl_blocks: u32,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn set_on_behalf_of(
+            &mut self,
+            target: ProxyTarget,
+            enabled: bool,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn set_rate_limit(
+            &mut self,
+            route: ProxyRoute,
+            max_calls: u32,
+            window_blocks: u32,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn set_route_fee(
+            &mut self,
+            route: ProxyRoute,
+            fee: u128,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn withdraw_fees(
+            &mut self,
+            to: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = ProxyEvent, Args = Self::Args>;
+        fn contract_ids(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
         fn contracts_id(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn export_state(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn fees(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn idempotency_ttl(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn info(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn metrics(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn on_behalf_of(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+        fn rate_limits(&self) -> impl Query<Output = ProxyEvent, Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Relayer {
+        type Args;
+        fn relay_call(
+            &mut self,
+            public_key: [u8; 32],
+            operation: Vec<u8>,
+            nonce: u64,
+            deadline: u32,
+            signature: RelaySignature,
+        ) -> impl Call<Output = ProxyRelayerEvent, Args = Self::Args>;
+        fn nonce(&self, user: ActorId)
+            -> impl Query<Output = ProxyRelayerEvent, Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Sessions {
+        type Args;
+        fn accept_session_key(
+            &mut self,
+            owner: ActorId,
+        ) -> impl Call<Output = ProxySessionsEvent, Args = Self::Args>;
+        fn register_session_key(
+            &mut self,
+            session_key: ActorId,
+            scope: Vec<ProxyRoute>,
+            expires_at: u32,
+            max_calls: u32,
+        ) -> impl Call<Output = ProxySessionsEvent, Args = Self::Args>;
+        fn revoke_session_key(
+            &mut self,
+            session_key: ActorId,
+        ) -> impl Call<Output = ProxySessionsEvent, Args = Self::Args>;
+        fn session_key(
+            &self,
+            session_key: ActorId,
+        ) -> impl Query<Output = ProxySessionsEvent, Args = Self::Args>;
+        fn session_keys(
+            &self,
+            owner: ActorId,
+        ) -> impl Query<Output = ProxySessionsEvent, Args = Self::Args>;
     }
 
     #[allow(clippy::type_complexity)]
@@ -498,12 +1702,24 @@ pub mod traits {
         fn call_green(
             &mut self,
         ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
+        fn call_green_idempotent(
+            &mut self,
+            idempotency_key: H256,
+        ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
         fn call_red(
             &mut self,
         ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
+        fn call_red_idempotent(
+            &mut self,
+            idempotency_key: H256,
+        ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
         fn call_yellow(
             &mut self,
         ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
+        fn call_yellow_idempotent(
+            &mut self,
+            idempotency_key: H256,
+        ) -> impl Call<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
         fn call_traffic_light_state(
             &self,
         ) -> impl Query<Output = ProxyTrafficLightCallerEvent, Args = Self::Args>;
@@ -1,6 +1,12 @@
 use sails_client_gen::ClientGenerator;
 use std::{env, fs, path::PathBuf};
-use app::ProxyProgram;
+use app::{
+    ProxyProgram,
+    program_info::{
+        IDL_HASH,
+        IDL_PATH
+    }
+};
 
 fn main() {
     // Build contract to get .opt.wasm
@@ -15,21 +21,43 @@ fn main() {
     // to store files generated at compile time. 
     let outdir_path = PathBuf::from(env::var("OUT_DIR").unwrap());
 
-    // Path where the file "app.idl" will be created
-    let idl_path = cargo_toml_path.clone().join("app.idl");
+    // Path where the file "app.idl" will be created, the builds without all the
+    // caller features create it in a directory named by their features (see IDL_PATH
+    // in app/build.rs), so the committed IDL is not changed
+    let idl_path = cargo_toml_path.clone().join(IDL_PATH);
+    let idl_dir = idl_path.parent().unwrap().to_path_buf();
+    fs::create_dir_all(&idl_dir).unwrap();
     let client_path = outdir_path.clone().join("app_client.rs");
 
     // This generate the contract IDL
     sails_idl_gen::generate_idl_to_file::<ProxyProgram>(idl_path.clone())
         .unwrap();
 
+    // The app embeds the info of the committed IDL (see app/build.rs), the build
+    // fails if the IDL changed in this build, so the info is never stale
+    build_info::check_idl(&idl_path, IDL_HASH);
+
+    // Compare the IDL with the last released one (app.released.idl), the build fails
+    // if it has breaking changes that are not acknowledged in idl_breaking_changes.txt.
+    // The released IDL has all the targets, so only the full build is compared
+    if IDL_PATH == "app.idl" {
+        let released_idl_path = cargo_toml_path.join("app.released.idl");
+        let acknowledged_path = cargo_toml_path.join("idl_breaking_changes.txt");
+        println!("cargo:rerun-if-changed={}", released_idl_path.display());
+        println!("cargo:rerun-if-changed={}", acknowledged_path.display());
+
+        if let Err(error) = idl_compat::check_files(&released_idl_path, &idl_path, Some(&acknowledged_path)) {
+            panic!("{error}");
+        }
+    }
+
     // Generator of the clients of the contract
     ClientGenerator::from_idl_path(&idl_path)
         .generate_to(client_path.clone())
         .unwrap();
 
-    // Then, copies the client that is in the OUT_DIR path in the current directory (wasm), where the 
-    // "Cargo.toml" file is located 
-    fs::copy(client_path, cargo_toml_path.join("app_client.rs"))
+    // Then, copies the client that is in the OUT_DIR path next to the IDL (for the full
+    // build it is the current directory (wasm), where the "Cargo.toml" file is located)
+    fs::copy(client_path, idl_dir.join("app_client.rs"))
         .unwrap();
 }
@@ -1,4 +1,13 @@
 #![no_std]
 
 #[cfg(target_arch = "wasm32")]
-pub use app::wasm::*;
\ No newline at end of file
+pub use app::wasm::*;
+
+// Optimized wasm of the program, it is used by the tests to deploy it in gtest
+#[cfg(not(target_arch = "wasm32"))]
+pub use code::WASM_BINARY_OPT as WASM_BINARY;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod code {
+    include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+}
@@ -0,0 +1,90 @@
+// Helpers of the gtest tests: the clients of the contracts, the gtest system
+// and the deployment of the programs
+#![allow(dead_code)]
+use sails_rs::{
+    calls::*,
+    gtest::{
+        calls::*,
+        System
+    },
+    prelude::*
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Mutex
+};
+
+// The variants of ProxyRoute are the names of the routes ("Call...")
+#[allow(clippy::enum_variant_names)]
+pub mod proxy_client {
+    include!("../../app_client.rs");
+}
+
+pub mod ping_client {
+    include!("../../../../ping_pong_contract/wasm/app_client.rs");
+}
+
+pub mod traffic_light_client {
+    include!("../../../../traffic_light_contract/wasm/app_client.rs");
+}
+
+pub mod fault_injection_client {
+    include!("../../../../fault_injection_contract/wasm/app_client.rs");
+}
+
+use proxy_client::traits::AppFactory as _;
+
+pub const ADMIN: u64 = 42;
+pub const USER: u64 = 43;
+const INITIAL_BALANCE: u128 = 100_000_000_000_000;
+
+// Wasm files of the target contracts that were already built
+static TARGET_WASMS: Mutex<Option<HashMap<&'static str, Vec<u8>>>> = Mutex::new(None);
+
+// Creates the gtest system with the balances of the admin and the user, the
+// messages are sent by the admin unless other actor is set
+pub fn remoting() -> GTestRemoting {
+    let system = System::new();
+    system.init_logger();
+    system.mint_to(ADMIN, INITIAL_BALANCE);
+    system.mint_to(USER, INITIAL_BALANCE);
+
+    GTestRemoting::new(system, ADMIN.into())
+}
+
+// Deploys the proxy, it is created with "NewWithContractsId" if the ids of the
+// ping and traffic light contracts are given, or with "New"
+pub async fn deploy_proxy(remoting: &GTestRemoting, contract_ids: Option<(ActorId, ActorId)>) -> ActorId {
+    let proxy_code = remoting.system().submit_code(wasm::WASM_BINARY);
+    let proxy_factory = proxy_client::AppFactory::new(remoting.clone());
+
+    match contract_ids {
+        Some((ping, traffic_light)) => proxy_factory
+            .new_with_contracts_id(ping, traffic_light)
+            .send_recv(proxy_code, b"proxy")
+            .await
+            .unwrap(),
+        None => proxy_factory
+            .new()
+            .send_recv(proxy_code, b"proxy")
+            .await
+            .unwrap()
+    }
+}
+
+// Returns the optimized wasm of a target contract, the contracts are in their own
+// workspaces (next to the proxy), so they are built here once for all the tests
+pub fn target_wasm(contract: &'static str) -> Vec<u8> {
+    let mut wasms = TARGET_WASMS.lock().unwrap_or_else(|error| error.into_inner());
+
+    wasms
+        .get_or_insert_with(HashMap::new)
+        .entry(contract)
+        .or_insert_with(|| {
+            let contract_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..").join(contract);
+
+            contract_wasm::build(&contract_dir).unwrap_or_else(|error| std::panic!("{error}"))
+        })
+        .clone()
+}
@@ -0,0 +1,180 @@
+// Tests of the error paths of the proxy, the proxy is pointed at the fault
+// injection program (it implements the Ping and TrafficLight services) and the
+// faults are set before each call, so the targets panic, run out of gas, reply
+// late or reply with bytes that the proxy can't decode.
+use sails_rs::{
+    calls::*,
+    gtest::calls::*,
+    prelude::*
+};
+
+mod common;
+
+use common::{
+    deploy_proxy,
+    fault_injection_client,
+    proxy_client,
+    remoting,
+    target_wasm,
+    USER
+};
+
+use proxy_client::{
+    traits::{
+        PingCaller as _,
+        TrafficLightCaller as _
+    },
+    PingEnum,
+    ProxyPingCallerError,
+    ProxyPingCallerEvent,
+    ProxyTrafficLightCallerError,
+    ProxyTrafficLightCallerEvent,
+    TrafficLightEvent
+};
+use fault_injection_client::{
+    traits::{
+        AppFactory as _,
+        Faults as _,
+        Ping as _
+    },
+    Fault,
+    FaultsErrors,
+    FaultsEvent,
+    PingReply
+};
+
+// Ids of the deployed programs
+struct Programs {
+    faults: ActorId,
+    proxy: ActorId
+}
+
+#[tokio::test]
+async fn target_panics_are_reported() {
+    assert_fault_is_reported(Fault::Panic).await;
+}
+
+#[tokio::test]
+async fn target_running_out_of_gas_is_reported() {
+    assert_fault_is_reported(Fault::OutOfGas).await;
+}
+
+#[tokio::test]
+async fn malformed_target_replies_are_reported() {
+    assert_fault_is_reported(Fault::MalformedReply).await;
+}
+
+#[tokio::test]
+async fn delayed_target_replies_are_returned() {
+    let remoting = remoting();
+    let programs = deploy(&remoting).await;
+    set_fault(&remoting, &programs, Fault::Delay(3)).await;
+
+    let user_remoting = remoting.clone().with_actor_id(USER.into());
+
+    let reply = proxy_client::PingCaller::new(user_remoting.clone())
+        .call_ping()
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    let reply = proxy_client::TrafficLightCaller::new(user_remoting)
+        .call_green()
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(
+        reply,
+        ProxyTrafficLightCallerEvent::TrafficLightContractResponse(TrafficLightEvent::Green)
+    );
+
+    // The call was stored for the user once the target woke up
+    let last_who_call = fault_injection_client::Ping::new(remoting)
+        .last_who_call()
+        .recv(programs.faults)
+        .await
+        .unwrap();
+    assert_eq!(last_who_call, (USER.into(), PingReply::Pong));
+}
+
+#[tokio::test]
+async fn non_admins_cannot_set_faults() {
+    let remoting = remoting();
+    let programs = deploy(&remoting).await;
+
+    let reply = fault_injection_client::Faults::new(remoting.clone().with_actor_id(USER.into()))
+        .set_fault(Fault::Panic)
+        .send_recv(programs.faults)
+        .await
+        .unwrap();
+    assert_eq!(reply, FaultsEvent::Error(FaultsErrors::OnlyAdminCanSetFault));
+
+    let fault = fault_injection_client::Faults::new(remoting)
+        .fault()
+        .recv(programs.faults)
+        .await
+        .unwrap();
+    assert_eq!(fault, Fault::None);
+}
+
+// Sets the fault in the target and checks that the ping and traffic light
+// routes of the proxy return the "ContractError" of each caller
+async fn assert_fault_is_reported(fault: Fault) {
+    let remoting = remoting();
+    let programs = deploy(&remoting).await;
+    set_fault(&remoting, &programs, fault.clone()).await;
+
+    let user_remoting = remoting.clone().with_actor_id(USER.into());
+
+    let reply = proxy_client::PingCaller::new(user_remoting.clone())
+        .call_ping()
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert!(
+        matches!(reply, ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractError(_))),
+        "{fault:?}: {reply:?}"
+    );
+
+    let reply = proxy_client::TrafficLightCaller::new(user_remoting)
+        .call_green()
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert!(
+        matches!(
+            reply,
+            ProxyTrafficLightCallerEvent::Error(ProxyTrafficLightCallerError::TrafficLightContractError(_))
+        ),
+        "{fault:?}: {reply:?}"
+    );
+}
+
+// Deploys the fault injection program and the proxy, the program is set as
+// the ping and the traffic light contract of the proxy
+async fn deploy(remoting: &GTestRemoting) -> Programs {
+    let faults_code = remoting.system().submit_code(target_wasm("fault_injection_contract"));
+    let faults = fault_injection_client::AppFactory::new(remoting.clone())
+        .new()
+        .send_recv(faults_code, b"faults")
+        .await
+        .unwrap();
+
+    let proxy = deploy_proxy(remoting, Some((faults, faults))).await;
+
+    Programs {
+        faults,
+        proxy
+    }
+}
+
+// Sets the fault of the target with the admin (the account that deployed it)
+async fn set_fault(remoting: &GTestRemoting, programs: &Programs, fault: Fault) {
+    let reply = fault_injection_client::Faults::new(remoting.clone())
+        .set_fault(fault.clone())
+        .send_recv(programs.faults)
+        .await
+        .unwrap();
+    assert_eq!(reply, FaultsEvent::FaultSet(fault));
+}
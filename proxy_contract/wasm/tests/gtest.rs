@@ -0,0 +1,402 @@
+// End to end tests of the proxy, they deploy the ping, traffic light and proxy
+// programs in gtest (a local node, no network is needed) and send the messages
+// with the generated clients (app_client.rs) of each contract.
+use sails_rs::{
+    calls::*,
+    gtest::calls::*,
+    prelude::*
+};
+
+mod common;
+
+use common::{
+    deploy_proxy,
+    ping_client,
+    proxy_client,
+    remoting,
+    target_wasm,
+    traffic_light_client,
+    USER
+};
+
+use proxy_client::{
+    traits::{
+        PingCaller as _,
+        Proxy as _,
+        TrafficLightCaller as _
+    },
+    ContractsId,
+    PingEnum,
+    ProxyErrors,
+    ProxyEvent,
+    ProxyPingCallerError,
+    ProxyPingCallerEvent,
+    ProxyRoute,
+    ProxyTarget,
+    ProxyTrafficLightCallerError,
+    ProxyTrafficLightCallerEvent,
+    TrafficLightEvent
+};
+use ping_client::traits::{
+    AppFactory as _,
+    Ping as _
+};
+use traffic_light_client::traits::{
+    AppFactory as _,
+    TrafficLight as _
+};
+
+// Ids of the deployed programs
+struct Programs {
+    ping: ActorId,
+    traffic_light: ActorId,
+    proxy: ActorId
+}
+
+#[tokio::test]
+async fn proxy_routes_reach_the_targets() {
+    let remoting = remoting();
+    let programs = deploy(&remoting, true).await;
+    trust_proxy(&remoting, &programs).await;
+
+    let user_remoting = remoting.clone().with_actor_id(USER.into());
+    let mut ping_caller = proxy_client::PingCaller::new(user_remoting.clone());
+    let mut traffic_light_caller = proxy_client::TrafficLightCaller::new(user_remoting.clone());
+
+    // Ping routes
+    let reply = ping_caller.call_ping().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    let reply = ping_caller.call_pong().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyPingCallerEvent::PingContractResponse(PingEnum::Ping));
+
+    // The calls are stored for the user, not for the proxy
+    let ping = ping_client::Ping::new(remoting.clone());
+    let all_calls = ping.all_calls().recv(programs.ping).await.unwrap();
+    assert_eq!(
+        all_calls,
+        vec![
+            (USER.into(), ping_client::PingEnum::Ping),
+            (USER.into(), ping_client::PingEnum::Pong)
+        ]
+    );
+
+    let last_who_call = ping.last_who_call().recv(programs.ping).await.unwrap();
+    assert_eq!(last_who_call, (USER.into(), ping_client::PingEnum::Pong));
+
+    let reply = ping_caller.call_all_calls().recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyPingCallerEvent::PingContractStateAllCalls(vec![
+            (USER.into(), PingEnum::Ping),
+            (USER.into(), PingEnum::Pong)
+        ])
+    );
+
+    let reply = ping_caller.call_last_who_call().recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyPingCallerEvent::PingContractStateLastWhoCall((USER.into(), PingEnum::Pong))
+    );
+
+    // Traffic light routes
+    let traffic_light = traffic_light_client::TrafficLight::new(remoting.clone());
+    let routes = [
+        (TrafficLightEvent::Green, "Green"),
+        (TrafficLightEvent::Yellow, "Yellow"),
+        (TrafficLightEvent::Red, "Red")
+    ];
+
+    for (event, light) in routes {
+        let reply = match event {
+            TrafficLightEvent::Green => traffic_light_caller.call_green().send_recv(programs.proxy).await,
+            TrafficLightEvent::Yellow => traffic_light_caller.call_yellow().send_recv(programs.proxy).await,
+            TrafficLightEvent::Red => traffic_light_caller.call_red().send_recv(programs.proxy).await
+        };
+        assert_eq!(reply.unwrap(), ProxyTrafficLightCallerEvent::TrafficLightContractResponse(event));
+
+        let state = traffic_light.traffic_light().recv(programs.traffic_light).await.unwrap();
+        assert_eq!(state.current_light, light);
+        assert_eq!(state.all_users, vec![(USER.into(), light.to_string())]);
+    }
+
+    let reply = traffic_light_caller.call_traffic_light_state().recv(programs.proxy).await.unwrap();
+    let ProxyTrafficLightCallerEvent::TrafficLightContractState(state) = reply else {
+        std::panic!("Unexpected reply: {reply:?}");
+    };
+    assert_eq!(state.current_light, "Red");
+
+    // Every route was called once and succeeded
+    let ProxyEvent::Metrics(metrics) = proxy_client::Proxy::new(remoting.clone())
+        .metrics()
+        .recv(programs.proxy)
+        .await
+        .unwrap()
+    else {
+        std::panic!("Metrics expected");
+    };
+
+    assert_eq!(metrics.routes.len(), 5);
+    for (route, route_metrics) in metrics.routes {
+        assert_eq!((route_metrics.calls, route_metrics.successes), (1, 1), "{route:?}");
+    }
+}
+
+#[tokio::test]
+async fn calls_fail_until_the_contract_ids_are_set() {
+    let remoting = remoting();
+    let programs = deploy(&remoting, false).await;
+    trust_proxy(&remoting, &programs).await;
+
+    let user_remoting = remoting.clone().with_actor_id(USER.into());
+    let mut ping_caller = proxy_client::PingCaller::new(user_remoting.clone());
+    let mut traffic_light_caller = proxy_client::TrafficLightCaller::new(user_remoting);
+
+    let reply = ping_caller.call_ping().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractIdNotSet)
+    );
+
+    let reply = traffic_light_caller.call_green().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyTrafficLightCallerEvent::Error(ProxyTrafficLightCallerError::TrafficLightContractIdNotSet)
+    );
+
+    let reply = ping_caller.call_all_calls().recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractIdNotSet)
+    );
+
+    let mut proxy = proxy_client::Proxy::new(remoting.clone());
+    let reply = proxy.contract_ids().recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyEvent::ContractIds(vec![]));
+
+    let reply = proxy
+        .change_contract_id(ProxyTarget::PingContract, programs.ping)
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyEvent::ContractIdSet(ProxyTarget::PingContract));
+
+    let reply = ping_caller.call_ping().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    // The traffic light id is still not set
+    let reply = traffic_light_caller.call_green().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyTrafficLightCallerEvent::Error(ProxyTrafficLightCallerError::TrafficLightContractIdNotSet)
+    );
+
+    // The methods of the first release are still supported
+    let reply = proxy
+        .change_traffic_light_contract_id(programs.traffic_light)
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyEvent::TrafficLightContractIdSet);
+
+    let reply = proxy.contracts_id().recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyEvent::ContractsId(ContractsId {
+            ping_contract_id: Some(programs.ping),
+            traffic_light_contract_id: Some(programs.traffic_light)
+        })
+    );
+}
+
+#[tokio::test]
+async fn non_admins_cannot_configure_the_proxy() {
+    let remoting = remoting();
+    let programs = deploy(&remoting, true).await;
+
+    let mut proxy = proxy_client::Proxy::new(remoting.clone().with_actor_id(USER.into()));
+
+    let reply = proxy
+        .change_contract_id(ProxyTarget::PingContract, USER.into())
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanChangeContactId));
+
+    let reply = proxy.add_admin(USER.into()).send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanChangeContactId));
+
+    let reply = proxy.reset_metrics().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanResetMetrics));
+
+    let reply = proxy
+        .set_rate_limit(ProxyRoute::CallPing, 1, 10)
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanChangeRateLimits));
+
+    let reply = proxy
+        .set_route_fee(ProxyRoute::CallPing, 1)
+        .send_recv(programs.proxy)
+        .await
+        .unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanManageFees));
+
+    let reply = proxy.set_idempotency_ttl(10).send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanChangeIdempotencyTtl));
+
+    let ProxyEvent::StateExport(export) = proxy.export_state().recv(programs.proxy).await.unwrap() else {
+        std::panic!("State export expected");
+    };
+    let reply = proxy.import_state(export).send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyEvent::Error(ProxyErrors::OnlyAdminsCanImportState));
+
+    // The contract ids were not changed
+    let reply = proxy.contract_ids().recv(programs.proxy).await.unwrap();
+    assert_eq!(
+        reply,
+        ProxyEvent::ContractIds(vec![
+            (ProxyTarget::PingContract, programs.ping),
+            (ProxyTarget::TrafficLightContract, programs.traffic_light)
+        ])
+    );
+}
+
+#[tokio::test]
+async fn untrusted_proxies_send_the_plain_commands() {
+    let remoting = remoting();
+    let programs = deploy(&remoting, true).await;
+
+    let mut ping_caller = proxy_client::PingCaller::new(remoting.clone().with_actor_id(USER.into()));
+    let reply = ping_caller.call_ping().send_recv(programs.proxy).await.unwrap();
+    assert_eq!(reply, ProxyPingCallerEvent::PingContractResponse(PingEnum::Pong));
+
+    // The ping contract does not trust the proxy, so the call is stored for the proxy
+    let all_calls = ping_client::Ping::new(remoting.clone())
+        .all_calls()
+        .recv(programs.ping)
+        .await
+        .unwrap();
+    assert_eq!(all_calls, vec![(programs.proxy, ping_client::PingEnum::Ping)]);
+}
+
+#[tokio::test]
+async fn target_panics_are_returned_as_errors() {
+    let remoting = remoting();
+    // The proxy sends the commands on behalf of the users, but it is not added
+    // as a trusted proxy in the targets, so the targets panic
+    let programs = deploy(&remoting, true).await;
+    send_on_behalf_of(&remoting, &programs).await;
+
+    let user_remoting = remoting.clone().with_actor_id(USER.into());
+    let mut ping_caller = proxy_client::PingCaller::new(user_remoting.clone());
+    let mut traffic_light_caller = proxy_client::TrafficLightCaller::new(user_remoting);
+
+    let reply = ping_caller.call_ping().send_recv(programs.proxy).await.unwrap();
+    assert!(
+        matches!(reply, ProxyPingCallerEvent::Error(ProxyPingCallerError::PingContractError(_))),
+        "{reply:?}"
+    );
+
+    let reply = traffic_light_caller.call_red().send_recv(programs.proxy).await.unwrap();
+    assert!(
+        matches!(reply, ProxyTrafficLightCallerEvent::Error(ProxyTrafficLightCallerError::TrafficLightContractError(_))),
+        "{reply:?}"
+    );
+
+    // The state of the targets did not change
+    let all_calls = ping_client::Ping::new(remoting.clone())
+        .all_calls()
+        .recv(programs.ping)
+        .await
+        .unwrap();
+    assert!(all_calls.is_empty());
+
+    let state = traffic_light_client::TrafficLight::new(remoting.clone())
+        .traffic_light()
+        .recv(programs.traffic_light)
+        .await
+        .unwrap();
+    assert!(state.all_users.is_empty());
+
+    // The failed calls are stored in the metrics
+    let ProxyEvent::Metrics(metrics) = proxy_client::Proxy::new(remoting)
+        .metrics()
+        .recv(programs.proxy)
+        .await
+        .unwrap()
+    else {
+        std::panic!("Metrics expected");
+    };
+
+    for route in [ProxyRoute::CallPing, ProxyRoute::CallRed] {
+        let (_, route_metrics) = metrics.routes
+            .iter()
+            .find(|(metrics_route, _)| *metrics_route == route)
+            .unwrap();
+
+        assert_eq!((route_metrics.calls, route_metrics.failures), (1, 1), "{route:?}");
+    }
+}
+
+// Deploys the target contracts and the proxy, the proxy is created with
+// "NewWithContractsId" or with "New" (without the ids of the targets)
+async fn deploy(remoting: &GTestRemoting, with_contract_ids: bool) -> Programs {
+    let ping_code = remoting.system().submit_code(target_wasm("ping_pong_contract"));
+    let ping = ping_client::AppFactory::new(remoting.clone())
+        .new()
+        .send_recv(ping_code, b"ping")
+        .await
+        .unwrap();
+
+    let traffic_light_code = remoting.system().submit_code(target_wasm("traffic_light_contract"));
+    let traffic_light = traffic_light_client::AppFactory::new(remoting.clone())
+        .new()
+        .send_recv(traffic_light_code, b"traffic_light")
+        .await
+        .unwrap();
+
+    let proxy_contract_ids = with_contract_ids.then_some((ping, traffic_light));
+    let proxy = deploy_proxy(remoting, proxy_contract_ids).await;
+
+    Programs {
+        ping,
+        traffic_light,
+        proxy
+    }
+}
+
+// Adds the proxy as a trusted proxy of the targets (with the admin of them), and
+// enables the commands on behalf of the users in the proxy
+async fn trust_proxy(remoting: &GTestRemoting, programs: &Programs) {
+    let reply = ping_client::Ping::new(remoting.clone())
+        .add_trusted_proxy(programs.proxy)
+        .send_recv(programs.ping)
+        .await
+        .unwrap();
+    assert_eq!(reply, ping_client::PingAdminEvent::TrustedProxyAdded(programs.proxy));
+
+    let reply = traffic_light_client::TrafficLight::new(remoting.clone())
+        .add_trusted_proxy(programs.proxy)
+        .send_recv(programs.traffic_light)
+        .await
+        .unwrap();
+    assert_eq!(reply, traffic_light_client::TrafficLightAdminEvent::TrustedProxyAdded(programs.proxy));
+
+    send_on_behalf_of(remoting, programs).await;
+}
+
+// Enables the commands on behalf of the users for both targets in the proxy
+async fn send_on_behalf_of(remoting: &GTestRemoting, programs: &Programs) {
+    let mut proxy = proxy_client::Proxy::new(remoting.clone());
+
+    for target in [ProxyTarget::PingContract, ProxyTarget::TrafficLightContract] {
+        let reply = proxy
+            .set_on_behalf_of(target.clone(), true)
+            .send_recv(programs.proxy)
+            .await
+            .unwrap();
+        assert_eq!(reply, ProxyEvent::OnBehalfOfSet((target, true)));
+    }
+}
@@ -0,0 +1,248 @@
+// Scenarios of the scenario runner, they are written in TOML or YAML files with:
+// - The actors (named accounts) that send the messages.
+// - The programs to deploy: the contract directory, the constructor and its arguments.
+// - The steps: messages from an actor to a program, with the route, the arguments
+//   and the expected reply (or that the message fails).
+//
+// The arguments and the replies are JSON values (see payload_cli), the strings
+// "$name" are replaced by the id of the actor or program with that name, and the
+// string "null" is a None (TOML has no null value).
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path
+};
+
+// Balance of the actors when the scenario starts
+pub const DEFAULT_ACTOR_BALANCE: u128 = 100_000_000_000_000;
+
+// Scenario that is read from a file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    // Name and number of the account of each actor
+    pub actors: BTreeMap<String, u64>,
+    pub programs: Vec<ProgramSpec>,
+    #[serde(default)]
+    pub steps: Vec<Step>
+}
+
+// Program to deploy, "contract" is the directory of the contract in the
+// repository (like "ping_pong_contract")
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProgramSpec {
+    pub name: String,
+    pub contract: String,
+    #[serde(default = "default_constructor")]
+    pub constructor: String,
+    #[serde(default)]
+    pub args: Vec<Value>,
+    pub from: String
+}
+
+// Message sent by an actor to a program, "call" is the route ("Service/Method")
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Step {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub from: String,
+    pub to: String,
+    pub call: String,
+    #[serde(default)]
+    pub args: Vec<Value>,
+    #[serde(default)]
+    pub value: u128,
+    // Expected reply, it is not checked if it is not set
+    #[serde(default)]
+    pub expect: Option<Value>,
+    // The message has to fail (like a panic of the program)
+    #[serde(default)]
+    pub expect_error: bool
+}
+
+// Result of a step (or of the deployment of a program)
+#[derive(Clone, Debug)]
+pub struct StepResult {
+    pub name: String,
+    pub error: Option<String>
+}
+
+// Report of a scenario with the result of each step
+#[derive(Clone, Debug)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub results: Vec<StepResult>
+}
+
+fn default_constructor() -> String {
+    "New".to_string()
+}
+
+impl Scenario {
+    // Reads a scenario from a TOML (".toml") or YAML (".yaml" or ".yml") file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("Can't read {}: {error}", path.display()))?;
+
+        let scenario: Scenario = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&text).map_err(|error| error.to_string()),
+            Some("yaml" | "yml") => serde_yaml::from_str(&text).map_err(|error| error.to_string()),
+            _ => Err("The scenario has to be a .toml, .yaml or .yml file".to_string())
+        }
+        .map_err(|error| format!("Can't parse {}: {error}", path.display()))?;
+
+        scenario.validate()?;
+
+        Ok(scenario)
+    }
+
+    // Checks that the actors and programs of the steps exist, so the scenario
+    // fails before the programs are built
+    pub fn validate(&self) -> Result<(), String> {
+        for program in &self.programs {
+            if self.actors.contains_key(&program.name) {
+                return Err(format!("The program {} has the name of an actor", program.name));
+            }
+
+            if !self.actors.contains_key(&program.from) {
+                return Err(format!("Unknown actor {} in the program {}", program.from, program.name));
+            }
+        }
+
+        for (index, step) in self.steps.iter().enumerate() {
+            if !self.actors.contains_key(&step.from) {
+                return Err(format!("Unknown actor {} in the step {}", step.from, index + 1));
+            }
+
+            if !self.programs.iter().any(|program| program.name == step.to) {
+                return Err(format!("Unknown program {} in the step {}", step.to, index + 1));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Step {
+    // Returns the name of the step, by default "from -> to Service/Method"
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{} -> {} {}", self.from, self.to, self.call))
+    }
+}
+
+impl ScenarioReport {
+    pub fn passed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.error.is_none())
+            .count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    // Returns the report as text, one line for each step
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Scenario: {}\n", self.name);
+
+        for result in &self.results {
+            match &result.error {
+                None => text.push_str(&format!("  PASS {}\n", result.name)),
+                Some(error) => text.push_str(&format!("  FAIL {}\n       {error}\n", result.name))
+            }
+        }
+
+        text.push_str(&format!("  {} passed, {} failed\n", self.passed(), self.failed()));
+
+        text
+    }
+}
+
+// Replaces the "$name" strings by the ids (as "0x..." hex) and the "null"
+// strings by null, in a value and all its items
+pub fn resolve(value: &Value, ids: &BTreeMap<String, String>) -> Result<Value, String> {
+    match value {
+        Value::String(text) if text == "null" => Ok(Value::Null),
+        Value::String(text) => match text.strip_prefix('$') {
+            Some(name) => ids
+                .get(name)
+                .map(|id| Value::String(id.clone()))
+                .ok_or(format!("Unknown actor or program: ${name}")),
+            None => Ok(value.clone())
+        },
+        Value::Array(items) => items
+            .iter()
+            .map(|item| resolve(item, ids))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, item)| Ok((key.clone(), resolve(item, ids)?)))
+            .collect::<Result<_, String>>()
+            .map(Value::Object),
+        _ => Ok(value.clone())
+    }
+}
+
+// Compares the expected reply with the decoded one. The numbers are compared by
+// their value, so a big number written as a string matches the same number
+pub fn matches(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .zip(actual)
+                    .all(|(expected, actual)| matches(expected, actual))
+        },
+        (Value::Object(expected), Value::Object(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .all(|(key, expected)| actual.get(key).is_some_and(|actual| matches(expected, actual)))
+        },
+        (Value::Number(_) | Value::String(_), Value::Number(_) | Value::String(_)) => {
+            match (number_text(expected), number_text(actual), expected, actual) {
+                (Some(expected), Some(actual), _, _) => expected == actual,
+                // Hex strings are compared without case
+                (_, _, Value::String(expected), Value::String(actual)) if expected.starts_with("0x") => {
+                    expected.eq_ignore_ascii_case(actual)
+                },
+                _ => expected == actual
+            }
+        },
+        _ => expected == actual
+    }
+}
+
+// Returns the text of an integer, written as a number or as a string, without
+// the leading zeros (so "007" is the same number as 7)
+fn number_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::Number(number) if number.is_u64() || number.is_i64() => number.to_string(),
+        Value::String(text) => text.clone(),
+        _ => return None
+    };
+
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", text.as_str())
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    match digits.trim_start_matches('0') {
+        "" => Some("0".to_string()),
+        digits => Some(format!("{sign}{digits}"))
+    }
+}
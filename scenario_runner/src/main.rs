@@ -0,0 +1,56 @@
+// Command line tool to run integration scenarios written in TOML or YAML:
+//
+//     scenario-runner <scenario.toml | scenario.yaml>...
+//
+// Each scenario deploys its programs in a new gtest system, sends the messages of
+// its steps and checks the replies. It prints a pass/fail report for each step and
+// exits with an error if a step of any scenario failed.
+mod runner;
+
+use scenario_runner::Scenario;
+use std::{
+    env,
+    path::Path,
+    process::ExitCode
+};
+
+fn main() -> ExitCode {
+    let paths: Vec<String> = env::args().skip(1).collect();
+
+    if paths.is_empty() {
+        eprintln!("Usage: scenario-runner <scenario.toml | scenario.yaml>...");
+        return ExitCode::from(2);
+    }
+
+    // All the scenarios are read first, so a wrong file fails before the programs are built
+    let scenarios = match paths
+        .iter()
+        .map(|path| Scenario::load(Path::new(path)))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(scenarios) => scenarios,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed_scenarios = 0;
+
+    for scenario in &scenarios {
+        let report = runner::run(scenario);
+        print!("{}", report.to_text());
+
+        if report.failed() > 0 {
+            failed_scenarios += 1;
+        }
+    }
+
+    println!("{} scenarios passed, {failed_scenarios} failed", scenarios.len() - failed_scenarios);
+
+    if failed_scenarios > 0 {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
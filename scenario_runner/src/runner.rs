@@ -0,0 +1,198 @@
+// Runs a scenario in gtest (a local runtime): it builds and deploys the programs,
+// sends the messages of the steps and checks the replies. The payloads are
+// encoded and decoded with the IDL of each contract (payload_cli).
+use payload_cli::{
+    hex_encode,
+    PayloadCodec
+};
+use sails_rs::{
+    gtest::{
+        Program,
+        System
+    },
+    prelude::*
+};
+use scenario_runner::{
+    matches,
+    resolve,
+    ProgramSpec,
+    Scenario,
+    ScenarioReport,
+    Step,
+    StepResult,
+    DEFAULT_ACTOR_BALANCE
+};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    path::PathBuf
+};
+
+// Ids of the programs, they are deployed with consecutive ids
+const FIRST_PROGRAM_ID: u64 = 1_000;
+// Blocks that are run waiting for a reply (the targets can reply late)
+const MAX_REPLY_BLOCKS: u32 = 100;
+
+// Deployed program with the codec of its IDL
+struct DeployedProgram<'a> {
+    program: Program<'a>,
+    codec: PayloadCodec
+}
+
+// Reply of a message, the payload is the error message if it failed
+struct Reply {
+    payload: Vec<u8>,
+    is_success: bool
+}
+
+// Runs the scenario and returns the result of each step, the scenario stops
+// when a program can't be deployed
+pub fn run(scenario: &Scenario) -> ScenarioReport {
+    let system = System::new();
+    let mut report = ScenarioReport {
+        name: scenario.name.clone(),
+        results: Vec::new()
+    };
+
+    // Ids of the actors and programs, used to replace the "$name" strings
+    let mut ids: BTreeMap<String, String> = BTreeMap::new();
+
+    for (name, account) in &scenario.actors {
+        system.mint_to(*account, DEFAULT_ACTOR_BALANCE);
+        ids.insert(name.clone(), hex_encode(ActorId::from(*account).as_ref()));
+    }
+
+    for (index, program) in scenario.programs.iter().enumerate() {
+        ids.insert(program.name.clone(), hex_encode(ActorId::from(FIRST_PROGRAM_ID + index as u64).as_ref()));
+    }
+
+    let mut programs: BTreeMap<&str, DeployedProgram> = BTreeMap::new();
+
+    for (index, spec) in scenario.programs.iter().enumerate() {
+        let step_name = format!("deploy {} ({} {})", spec.name, spec.contract, spec.constructor);
+
+        let deployed = deploy(&system, FIRST_PROGRAM_ID + index as u64, spec, scenario, &ids);
+        let error = deployed.as_ref().err().cloned();
+
+        report.results.push(StepResult {
+            name: step_name,
+            error
+        });
+
+        match deployed {
+            Ok(deployed) => {
+                programs.insert(&spec.name, deployed);
+            },
+            Err(_) => return report
+        }
+    }
+
+    for step in &scenario.steps {
+        let target = &programs[step.to.as_str()];
+        let from = scenario.actors[&step.from];
+
+        let error = send_step(&system, target, from, step, &ids).err();
+
+        report.results.push(StepResult {
+            name: step.display_name(),
+            error
+        });
+    }
+
+    report
+}
+
+// Builds the contract, deploys it and sends the constructor
+fn deploy<'a>(
+    system: &'a System,
+    id: u64,
+    spec: &ProgramSpec,
+    scenario: &Scenario,
+    ids: &BTreeMap<String, String>
+) -> Result<DeployedProgram<'a>, String> {
+    let contract_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(&spec.contract);
+    let codec = PayloadCodec::load(&contract_dir.join("wasm/app.idl"))?;
+
+    let args = resolve_args(&spec.args, ids)?;
+    let payload = codec.encode_call(&spec.constructor, &args)?;
+
+    let program = Program::from_binary_with_id(system, id, contract_wasm::build(&contract_dir)?);
+    let reply = send(system, &program, scenario.actors[&spec.from], payload, 0)?;
+
+    if !reply.is_success {
+        return Err(format!("The constructor failed: {}", String::from_utf8_lossy(&reply.payload)));
+    }
+
+    Ok(DeployedProgram {
+        program,
+        codec
+    })
+}
+
+// Sends the message of a step and checks its reply
+fn send_step(
+    system: &System,
+    target: &DeployedProgram,
+    from: u64,
+    step: &Step,
+    ids: &BTreeMap<String, String>
+) -> Result<(), String> {
+    let args = resolve_args(&step.args, ids)?;
+    let payload = target.codec.encode_call(&step.call, &args)?;
+
+    let reply = send(system, &target.program, from, payload, step.value)?;
+
+    match (reply.is_success, step.expect_error) {
+        (true, true) => return Err("The message succeeded, but it was expected to fail".to_string()),
+        (false, false) => {
+            return Err(format!("The message failed: {}", String::from_utf8_lossy(&reply.payload)));
+        },
+        (false, true) => return Ok(()),
+        (true, false) => {}
+    }
+
+    let Some(expected) = &step.expect else {
+        return Ok(());
+    };
+
+    let expected = resolve(expected, ids)?;
+    let (_, actual) = target.codec.decode_reply(&reply.payload)?;
+
+    if !matches(&expected, &actual) {
+        return Err(format!("Expected reply: {expected}\n       Actual reply:   {actual}"));
+    }
+
+    Ok(())
+}
+
+fn resolve_args(args: &[Value], ids: &BTreeMap<String, String>) -> Result<Vec<Value>, String> {
+    args
+        .iter()
+        .map(|arg| resolve(arg, ids))
+        .collect()
+}
+
+// Sends the payload and runs blocks until the reply to the message arrives
+fn send(system: &System, program: &Program, from: u64, payload: Vec<u8>, value: u128) -> Result<Reply, String> {
+    let message_id = program.send_bytes_with_value(from, payload, value);
+
+    for _ in 0..MAX_REPLY_BLOCKS {
+        let result = system.run_next_block();
+
+        let reply = result.log
+            .iter()
+            .find(|log| log.reply_to() == Some(message_id));
+
+        if let Some(reply) = reply {
+            return Ok(Reply {
+                payload: reply.payload().to_vec(),
+                is_success: reply
+                    .reply_code()
+                    .map(|code| code.is_success())
+                    .unwrap_or(false)
+            });
+        }
+    }
+
+    Err(format!("No reply after {MAX_REPLY_BLOCKS} blocks"))
+}
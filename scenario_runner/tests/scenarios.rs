@@ -0,0 +1,117 @@
+// Tests of the scenarios: the loader, the "$name" references and the comparison
+// of the expected replies, they don't build or run the programs
+use scenario_runner::{
+    matches,
+    resolve,
+    Scenario
+};
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf
+    }
+};
+
+const SCENARIO: &str = r#"
+name = "Ping"
+
+[actors]
+admin = 42
+
+[[programs]]
+name = "ping"
+contract = "ping_pong_contract"
+from = "admin"
+
+[[steps]]
+from = "admin"
+to = "ping"
+call = "Ping/Ping"
+expect = "Pong"
+"#;
+
+// Writes the scenario in a temporary file with the given name
+fn scenario_file(file_name: &str, text: &str) -> PathBuf {
+    let dir = env::temp_dir().join("scenario_runner_tests");
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join(file_name);
+    fs::write(&path, text).unwrap();
+
+    path
+}
+
+#[test]
+fn scenarios_of_the_repository_are_loaded() {
+    let scenarios_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+
+    for file_name in ["proxy_ping.toml", "traffic_light.yaml"] {
+        let scenario = Scenario::load(&scenarios_dir.join(file_name)).unwrap();
+
+        assert!(!scenario.steps.is_empty(), "{file_name} has no steps");
+    }
+}
+
+#[test]
+fn scenario_is_loaded_with_the_defaults() {
+    let scenario = Scenario::load(&scenario_file("defaults.toml", SCENARIO)).unwrap();
+
+    assert_eq!(scenario.programs[0].constructor, "New");
+    assert_eq!(scenario.steps[0].value, 0);
+    assert!(!scenario.steps[0].expect_error);
+    assert_eq!(scenario.steps[0].display_name(), "admin -> ping Ping/Ping");
+}
+
+#[test]
+fn scenarios_with_unknown_names_are_rejected() {
+    for (file_name, text) in [
+        ("unknown_step_actor.toml", SCENARIO.replace("from = \"admin\"\nto", "from = \"alice\"\nto")),
+        ("unknown_program.toml", SCENARIO.replace("to = \"ping\"", "to = \"proxy\"")),
+        ("unknown_program_actor.toml", SCENARIO.replace("from = \"admin\"\n\n[[steps]]", "from = \"alice\"\n\n[[steps]]")),
+        ("unknown_field.toml", SCENARIO.replace("call = ", "method = ")),
+        ("unknown_extension.json", SCENARIO.to_string())
+    ] {
+        assert!(Scenario::load(&scenario_file(file_name, &text)).is_err(), "{file_name} was loaded");
+    }
+}
+
+#[test]
+fn names_and_nulls_are_resolved() {
+    let ids = BTreeMap::from([("ping".to_string(), "0x01".to_string())]);
+
+    assert_eq!(
+        resolve(&json!(["$ping", "null", { "Some": "$ping" }, 5, "ping"]), &ids).unwrap(),
+        json!(["0x01", null, { "Some": "0x01" }, 5, "ping"])
+    );
+    assert!(resolve(&json!({ "to": "$proxy" }), &ids).is_err());
+}
+
+#[test]
+fn numbers_are_matched_by_value() {
+    assert!(matches(&json!(7), &json!(7)));
+    assert!(matches(&json!("7"), &json!(7)));
+    assert!(matches(&json!("007"), &json!(7)));
+    assert!(matches(&json!(0), &json!("000")));
+    assert!(matches(&json!("-5"), &json!(-5)));
+    assert!(matches(&json!("340282366920938463463374607431768211455"), &json!("340282366920938463463374607431768211455")));
+
+    assert!(!matches(&json!(7), &json!(70)));
+    assert!(!matches(&json!("-7"), &json!(7)));
+    assert!(!matches(&json!("-"), &json!(0)));
+    assert!(!matches(&json!("7a"), &json!(7)));
+}
+
+#[test]
+fn values_are_matched_by_their_items() {
+    assert!(matches(&json!("0xABCD"), &json!("0xabcd")));
+    assert!(!matches(&json!("Ping"), &json!("ping")));
+
+    assert!(matches(&json!({ "Some": [1, "2"] }), &json!({ "Some": ["1", 2] })));
+    assert!(!matches(&json!([1, 2]), &json!([1, 2, 3])));
+    assert!(!matches(&json!({ "a": 1 }), &json!({ "a": 1, "b": 2 })));
+    assert!(!matches(&json!({ "a": 1 }), &json!({ "b": 1 })));
+}
@@ -0,0 +1,16 @@
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    // Path where the file "Cargo.toml" of the app is located
+    let cargo_toml_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let outdir_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // The IDL is generated by the wasm build, so the info uses the committed IDL
+    // (the wasm build fails if it changes, see wasm/build.rs)
+    let idl_path = cargo_toml_path.join("../wasm/app.idl");
+
+    fs::write(
+        outdir_path.join("program_info.rs"),
+        build_info::program_info(&idl_path)
+    ).unwrap();
+}
@@ -1,11 +1,15 @@
 #![no_std]
 
 // necesary crates
-use sails_rs::prelude::*;
+use sails_rs::{
+    prelude::*,
+    gstd::msg
+};
 
 // import our modules 
 pub mod states;
 pub mod services;
+pub mod program_info;
 
 // Import service to be used for the program
 use services::traffic_light_service::TrafficLightService;
@@ -21,8 +25,8 @@ impl TrafficLightProgram {
     // Application constructor (it is an associated function)
     // It can be called once per application lifetime.
     pub fn new() -> Self {
-        // Init the state
-        TrafficLightService::seed();
+        // Init the state, the caller will be the admin of the contract
+        TrafficLightService::seed(msg::source());
 
         Self
     }
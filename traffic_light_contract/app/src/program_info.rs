@@ -0,0 +1,46 @@
+// Necesary crates
+use sails_rs::prelude::*;
+
+// Constants generated by the build script (app/build.rs): the git revision, the
+// hash of the IDL (wasm/app.idl) and the services of the program
+include!(concat!(env!("OUT_DIR"), "/program_info.rs"));
+
+// Struct with the data to identify the build of the program that is deployed
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramInfo {
+    pub version: String,
+    pub git_rev: String,
+    pub idl_hash: H256,
+    pub services: Vec<ServiceInfo>
+}
+
+// Struct with the commands and queries of a service
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub commands: Vec<String>,
+    pub queries: Vec<String>
+}
+
+impl ProgramInfo {
+    // Related function to get the info of the current build
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_rev: GIT_REV.to_string(),
+            idl_hash: H256::from(IDL_HASH),
+            services: SERVICES
+                .iter()
+                .map(|(name, commands, queries)| ServiceInfo {
+                    name: name.to_string(),
+                    commands: commands.iter().map(|command| command.to_string()).collect(),
+                    queries: queries.iter().map(|query| query.to_string()).collect()
+                })
+                .collect()
+        }
+    }
+}
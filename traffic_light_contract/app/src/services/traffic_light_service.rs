@@ -9,6 +9,8 @@ use crate::states::traffic_light_state::{
     TrafficLightState,
     IoTrafficLightState
 };
+// import the info of the program
+use crate::program_info::ProgramInfo;
 
 // Traffic light service struct to build the service 
 #[derive(Default)]
@@ -20,8 +22,29 @@ impl TrafficLightService {
     // Another related function is created that initializes the state 
     // to avoid unnecessary imports in the "lib.rs" file, you can see 
     // that it remains more "structured"
-    pub fn seed() {
-        TrafficLightState::init_state();
+    pub fn seed(admin: ActorId) {
+        TrafficLightState::init_state(admin);
+    }
+
+    // Related function to change the light, the user is stored with the
+    // light that it set
+    fn set_light(user: ActorId, light: &str) {
+        let current_light = light.to_string();
+
+        // Changing state
+        TrafficLightState::state_mut()
+            .current_light = current_light.clone();
+        TrafficLightState::state_mut()
+            .all_users
+            .insert(user, current_light);
+    }
+
+    // Related function to check that the caller is a trusted proxy, only
+    // trusted proxies can send messages on behalf of the users
+    fn only_trusted_proxy() {
+        if !TrafficLightState::state_ref().is_trusted_proxy(msg::source()) {
+            panic!("Only trusted proxies can call on behalf of users");
+        }
     }
 }
 
@@ -37,18 +60,8 @@ impl TrafficLightService {
     // Returns a struct that will be sent as a response to the user
     // Is treated as a command changing the state (&mut self)
     pub fn green(&mut self) -> TrafficLightEvent {
-        // // Get state as mut
-        // let traffic_light_state = traffic_light_state_mut();
-
-        let current_light = "Green".to_string();
-
         // Changing state
-        TrafficLightState::state_mut()
-            .current_light = current_light.clone();
-
-        TrafficLightState::state_mut()
-            .all_users
-            .insert(msg::source().into(), current_light);
+        Self::set_light(msg::source(), "Green");
 
         // returning the response
         TrafficLightEvent::Green
@@ -58,17 +71,8 @@ impl TrafficLightService {
     // Returns a struct that will be sent as a response to the user
     // Is treated as a command changing the state (&mut self)
     pub fn yellow(&mut self) -> TrafficLightEvent {
-        // // Get state as mut
-        // let traffic_light_state = traffic_light_state_mut();
-
-        let current_light = "Yellow".to_string();
-
         // Changing state
-        TrafficLightState::state_mut()
-            .current_light = current_light.clone();
-        TrafficLightState::state_mut()
-            .all_users
-            .insert(msg::source().into(), current_light);
+        Self::set_light(msg::source(), "Yellow");
 
         // returning the response
         TrafficLightEvent::Yellow
@@ -78,17 +82,8 @@ impl TrafficLightService {
     // Returns a struct that will be sent as a response to the user
     // Is treated as a command changing the state (&mut self)
     pub fn red(&mut self) -> TrafficLightEvent {
-        // // Get state as mut
-        // let traffic_light_state = traffic_light_state_mut();
-
-        let current_light = "Red".to_string();
-
         // Changing state
-        TrafficLightState::state_mut()
-            .current_light = current_light.clone();
-        TrafficLightState::state_mut()
-            .all_users
-            .insert(msg::source().into(), current_light);
+        Self::set_light(msg::source(), "Red");
 
         // returning the response
         TrafficLightEvent::Red
@@ -102,6 +97,95 @@ impl TrafficLightService {
             .to_owned()
             .into()
     }
+
+    // Remote call "green_on_behalf_of", same as "green", but the light is stored
+    // for the given user. Only trusted proxies can call it (trusted forwarder)
+    pub fn green_on_behalf_of(&mut self, user: ActorId) -> TrafficLightEvent {
+        Self::only_trusted_proxy();
+
+        Self::set_light(user, "Green");
+
+        TrafficLightEvent::Green
+    }
+
+    // Remote call "yellow_on_behalf_of", same as "yellow", but the light is stored
+    // for the given user. Only trusted proxies can call it (trusted forwarder)
+    pub fn yellow_on_behalf_of(&mut self, user: ActorId) -> TrafficLightEvent {
+        Self::only_trusted_proxy();
+
+        Self::set_light(user, "Yellow");
+
+        TrafficLightEvent::Yellow
+    }
+
+    // Remote call "red_on_behalf_of", same as "red", but the light is stored
+    // for the given user. Only trusted proxies can call it (trusted forwarder)
+    pub fn red_on_behalf_of(&mut self, user: ActorId) -> TrafficLightEvent {
+        Self::only_trusted_proxy();
+
+        Self::set_light(user, "Red");
+
+        TrafficLightEvent::Red
+    }
+
+    // Remote call "add_trusted_proxy" to add a proxy that can send messages on
+    // behalf of the users. Only admins can call it
+    pub fn add_trusted_proxy(&mut self, proxy: ActorId) -> TrafficLightAdminEvent {
+        if !TrafficLightState::state_ref().is_admin(msg::source()) {
+            return TrafficLightAdminEvent::Error(
+                TrafficLightAdminError::OnlyAdminsCanChangeTrustedProxies
+            );
+        }
+
+        if TrafficLightState::state_ref().is_trusted_proxy(proxy) {
+            return TrafficLightAdminEvent::Error(
+                TrafficLightAdminError::TrustedProxyExists(proxy)
+            );
+        }
+
+        TrafficLightState::state_mut()
+            .trusted_proxies
+            .push(proxy);
+
+        TrafficLightAdminEvent::TrustedProxyAdded(proxy)
+    }
+
+    // Remote call "remove_trusted_proxy" to remove a trusted proxy
+    // Only admins can call it
+    pub fn remove_trusted_proxy(&mut self, proxy: ActorId) -> TrafficLightAdminEvent {
+        if !TrafficLightState::state_ref().is_admin(msg::source()) {
+            return TrafficLightAdminEvent::Error(
+                TrafficLightAdminError::OnlyAdminsCanChangeTrustedProxies
+            );
+        }
+
+        if !TrafficLightState::state_ref().is_trusted_proxy(proxy) {
+            return TrafficLightAdminEvent::Error(
+                TrafficLightAdminError::TrustedProxyNotFound(proxy)
+            );
+        }
+
+        TrafficLightState::state_mut()
+            .trusted_proxies
+            .retain(|trusted_proxy| *trusted_proxy != proxy);
+
+        TrafficLightAdminEvent::TrustedProxyRemoved(proxy)
+    }
+
+    // Remote call "trusted_proxies" exposed to external consumers
+    // Returns the proxies that can call on behalf of the users
+    pub fn trusted_proxies(&self) -> Vec<ActorId> {
+        TrafficLightState::state_ref()
+            .trusted_proxies
+            .to_owned()
+    }
+
+    // Remote call "info" exposed to external consumers
+    // Returns the version, git revision, IDL hash and services of the deployed
+    // build, to detect which build is deployed
+    pub fn info(&self) -> ProgramInfo {
+        ProgramInfo::current()
+    }
 }
 
 // struct to use as a response to the user
@@ -115,4 +199,22 @@ pub enum TrafficLightEvent {
     Red
 }
 
+// enum to use as a response to the admin methods
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightAdminEvent {
+    TrustedProxyAdded(ActorId),
+    TrustedProxyRemoved(ActorId),
+    Error(TrafficLightAdminError)
+}
 
+// enum to set the errors of the admin methods
+#[derive(Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TrafficLightAdminError {
+    OnlyAdminsCanChangeTrustedProxies,
+    TrustedProxyExists(ActorId),
+    TrustedProxyNotFound(ActorId)
+}
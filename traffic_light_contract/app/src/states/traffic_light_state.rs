@@ -13,25 +13,37 @@ pub static mut TRAFFIC_LIGHT_STATE: Option<TrafficLightState> = None;
 pub struct TrafficLightState {
     pub current_light: String,
     pub all_users: HashMap<ActorId, String>,
+    pub admins: Vec<ActorId>,
+    pub trusted_proxies: Vec<ActorId>,
 }
 
 // Impl to set methods or related functions in TrafficLightState
 impl TrafficLightState {
     // Method to create a new instance of TrafficLightState
-    pub fn new() -> Self {
+    pub fn new(admin: ActorId) -> Self {
         Self {
             current_light: "".to_string(),
-            all_users: HashMap::new()
+            all_users: HashMap::new(),
+            admins: vec![admin],
+            trusted_proxies: Vec::new()
         }
     }
 
     // Related function to init the state of traffic light (call once)
-    pub fn init_state() {
+    pub fn init_state(admin: ActorId) {
         unsafe {
-            TRAFFIC_LIGHT_STATE = Some(Self::new());
+            TRAFFIC_LIGHT_STATE = Some(Self::new(admin));
         };
     }
 
+    pub fn is_admin(&self, address: ActorId) -> bool {
+        self.admins.contains(&address)
+    }
+
+    pub fn is_trusted_proxy(&self, address: ActorId) -> bool {
+        self.trusted_proxies.contains(&address)
+    }
+
     // Related function to get the state as mut
     pub fn state_mut() -> &'static mut TrafficLightState {
         let state = unsafe { TRAFFIC_LIGHT_STATE.as_mut() };
@@ -65,6 +77,7 @@ impl From<TrafficLightState> for IoTrafficLightState {
         let TrafficLightState {
             current_light,
             all_users,
+            ..
         } = value;
 
         // Perform some transformation on second field, cloning its elements (Warning: Just for HashMaps!!)